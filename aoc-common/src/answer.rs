@@ -0,0 +1,71 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Unsolved
+}
+
+impl Answer {
+    // None for anything that isn't a number
+    pub fn as_number (&self) -> Option<i64> {
+        return match self {
+            Answer::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt (&self, f: &mut Formatter) -> std::fmt::Result {
+        return match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from (value: $t) -> Answer {
+                    return Answer::Number(value as i64);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! answer_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from (value: $t) -> Answer {
+                    // anything too big for an i64 is still a valid answer, just not a number we can do maths on
+                    return match i64::try_from(value) {
+                        Ok(n) => Answer::Number(n),
+                        Err(_) => Answer::Text(value.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_signed!(i32, i64);
+answer_from_unsigned!(u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from (value: String) -> Answer {
+        return Answer::Text(value);
+    }
+}
+
+impl From<&str> for Answer {
+    fn from (value: &str) -> Answer {
+        return Answer::Text(value.to_string());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{input::{self, InputSource}, log::{self, Level}, memory::MEMORY_ARG};
+
+pub const BENCH_ARG: &str = "--bench";
+pub const FORMAT_ARG: &str = "--format";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    // answers on stdout as plain text, everything else on stderr
+    #[default]
+    Human,
+    // a single JSON document on stdout for other tools to consume
+    Json
+}
+
+// the arguments every day's binary accepts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayArgs {
+    pub input: InputSource,
+    // repeat the run this many times and report timing stats instead of a single run
+    pub bench: Option<usize>,
+    // count allocations and report each phase's memory use next to its timing
+    pub memory: bool,
+    pub format: OutputFormat,
+    pub log_level: Level
+}
+
+impl DayArgs {
+    pub fn parse (args: &[String]) -> Result<DayArgs, String> {
+        let mut day_args = DayArgs { input: InputSource::Default, bench: None, memory: false, format: OutputFormat::Human, log_level: log::DEFAULT_LEVEL };
+        let mut has_input = false;
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            if arg == BENCH_ARG {
+                day_args.bench = Some(parse_bench(args.next())?);
+                continue;
+            }
+            if arg == MEMORY_ARG {
+                day_args.memory = true;
+                continue;
+            }
+            if arg == FORMAT_ARG {
+                day_args.format = parse_format(args.next())?;
+                continue;
+            }
+            if let Some(level) = log::verbosity_arg(arg, day_args.log_level) {
+                day_args.log_level = level;
+                continue;
+            }
+
+            if has_input {
+                return Err("expected at most one input argument".to_string());
+            }
+
+            day_args.input = if arg == input::EXAMPLE_ARG {
+                InputSource::Example
+            } else if arg.starts_with('-') && arg != input::STDIN_ARG {
+                return Err(format!("unknown argument: {}", arg));
+            } else {
+                InputSource::from_arg(arg)
+            };
+            has_input = true;
+        }
+
+        // counting slows down every allocation, which would skew the timings
+        if day_args.bench.is_some() && day_args.memory {
+            return Err(format!("expected only one of {} or {}", BENCH_ARG, MEMORY_ARG));
+        }
+
+        return Ok(day_args);
+    }
+}
+
+pub fn parse_bench (value: Option<&String>) -> Result<usize, String> {
+    return match value.map(|v| v.parse::<usize>()) {
+        Some(Ok(runs)) if runs > 0 => Ok(runs),
+        _ => Err(format!("{} expects a number of runs greater than 0", BENCH_ARG)),
+    }
+}
+
+pub fn parse_format (value: Option<&String>) -> Result<OutputFormat, String> {
+    return match value.map(|v| v.as_str()) {
+        Some("human") => Ok(OutputFormat::Human),
+        Some("json") => Ok(OutputFormat::Json),
+        _ => Err(format!("{} expects human or json", FORMAT_ARG)),
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// where parsing went wrong and what we were hoping to see there
+// line and column are both 1-based, the column counts characters not bytes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    // the offending line, for showing the error in context
+    pub source_line: String
+}
+
+impl ParseError {
+    pub fn at_column (line_number: usize, line: &str, column: usize, expected: impl Into<String>, found: impl Into<String>) -> ParseError {
+        return ParseError {
+            file: None,
+            line: line_number,
+            column: column,
+            expected: expected.into(),
+            found: found.into(),
+            source_line: line.to_string()
+        }
+    }
+
+    // `token` has to be a slice of `line`, so we can work out where it is
+    pub fn at_token (line_number: usize, line: &str, token: &str, expected: impl Into<String>) -> ParseError {
+        let found = if token.is_empty() { "nothing".to_string() } else { format!("{:?}", token) };
+        return ParseError::at_column(line_number, line, column_of(line, token), expected, found);
+    }
+
+    pub fn end_of_line (line_number: usize, line: &str, expected: impl Into<String>) -> ParseError {
+        return ParseError::at_column(line_number, line, line.chars().count() + 1, expected, "end of line");
+    }
+
+    // for things that are missing from the input as a whole
+    pub fn end_of_input (input: &str, expected: impl Into<String>) -> ParseError {
+        return ParseError::at_column(input.lines().count() + 1, "", 1, expected, "end of input");
+    }
+
+    pub fn in_file (mut self, file: impl Into<String>) -> ParseError {
+        self.file = Some(file.into());
+        return self;
+    }
+}
+
+impl Display for ParseError {
+    fn fmt (&self, f: &mut Formatter) -> std::fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "error: expected {}, found {}", self.expected, self.found)?;
+        writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column.saturating_sub(1)))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// 1-based character column of `token` within `line`
+// falls back to the start of the line if `token` isn't actually part of it
+pub fn column_of (line: &str, token: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+
+    if token_start < line_start || token_start > line_start + line.len() {
+        return 1;
+    }
+
+    let offset = token_start - line_start;
+    return line[..offset].chars().count() + 1;
+}
+
+// parses a token taken from `line`, reporting where it was if it doesn't parse
+pub fn parse_token<T: FromStr> (line_number: usize, line: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    return token.parse::<T>().map_err(|_| ParseError::at_token(line_number, line, token, expected));
+}
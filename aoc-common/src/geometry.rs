@@ -0,0 +1,241 @@
+use std::cmp::{max, min};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// points double as vectors, so the difference between two points is a Point2 too
+// y grows downwards like the rows of a grid, so north is (0, -1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new (x: i64, y: i64) -> Point2 {
+        return Point2 { x: x, y: y };
+    }
+
+    pub fn manhattan (&self, other: Point2) -> i64 {
+        return (self.x - other.x).abs() + (self.y - other.y).abs();
+    }
+
+    // the number of king moves between the two
+    pub fn chebyshev (&self, other: Point2) -> i64 {
+        return max((self.x - other.x).abs(), (self.y - other.y).abs());
+    }
+
+    // comparing squared distances avoids floating point, and orders the same as the real distance
+    pub fn squared_euclidean (&self, other: Point2) -> i64 {
+        return (self.x - other.x).pow(2) + (self.y - other.y).pow(2);
+    }
+
+    // each component clamped to -1, 0 or 1, i.e. a single step towards wherever this vector points
+    pub fn signum (&self) -> Point2 {
+        return Point2::new(self.x.signum(), self.y.signum());
+    }
+
+    pub fn step (&self, direction: Direction) -> Point2 {
+        return *self + direction.offset();
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new (x: i64, y: i64, z: i64) -> Point3 {
+        return Point3 { x: x, y: y, z: z };
+    }
+
+    pub fn manhattan (&self, other: Point3) -> i64 {
+        return (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs();
+    }
+
+    pub fn chebyshev (&self, other: Point3) -> i64 {
+        return max(max((self.x - other.x).abs(), (self.y - other.y).abs()), (self.z - other.z).abs());
+    }
+
+    pub fn squared_euclidean (&self, other: Point3) -> i64 {
+        return (self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2);
+    }
+
+    pub fn signum (&self) -> Point3 {
+        return Point3::new(self.x.signum(), self.y.signum(), self.z.signum());
+    }
+}
+
+// the same operators for both points, component by component
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add (self, other: $point) -> $point {
+                return $point { $($field: self.$field + other.$field),* };
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub (self, other: $point) -> $point {
+                return $point { $($field: self.$field - other.$field),* };
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul (self, scale: i64) -> $point {
+                return $point { $($field: self.$field * scale),* };
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg (self) -> $point {
+                return $point { $($field: -self.$field),* };
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign (&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign (&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+impl From<(i64, i64)> for Point2 {
+    fn from ((x, y): (i64, i64)) -> Point2 {
+        return Point2::new(x, y);
+    }
+}
+
+// grid coordinates
+impl From<(usize, usize)> for Point2 {
+    fn from ((x, y): (usize, usize)) -> Point2 {
+        return Point2::new(x as i64, y as i64);
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from ((x, y, z): (i64, i64, i64)) -> Point3 {
+        return Point3::new(x, y, z);
+    }
+}
+
+// listed clockwise, so turning right is the next one along
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn offset (&self) -> Point2 {
+        return match self {
+            Direction::North => Point2::new(0, -1),
+            Direction::East => Point2::new(1, 0),
+            Direction::South => Point2::new(0, 1),
+            Direction::West => Point2::new(-1, 0),
+        }
+    }
+
+    // a quarter turn clockwise
+    pub fn rotate_right (&self) -> Direction {
+        return Direction::ALL[(*self as usize + 1) % 4];
+    }
+
+    // a quarter turn anticlockwise
+    pub fn rotate_left (&self) -> Direction {
+        return Direction::ALL[(*self as usize + 3) % 4];
+    }
+
+    pub fn opposite (&self) -> Direction {
+        return Direction::ALL[(*self as usize + 2) % 4];
+    }
+}
+
+// the smallest axis-aligned rectangle holding some points, both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min: Point2,
+    pub max: Point2
+}
+
+impl BoundingBox {
+    pub fn from_corners (corner_1: Point2, corner_2: Point2) -> BoundingBox {
+        return BoundingBox {
+            min: Point2::new(min(corner_1.x, corner_2.x), min(corner_1.y, corner_2.y)),
+            max: Point2::new(max(corner_1.x, corner_2.x), max(corner_1.y, corner_2.y))
+        };
+    }
+
+    // None if there aren't any points to bound
+    pub fn from_points (points: impl IntoIterator<Item = Point2>) -> Option<BoundingBox> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        let mut bounding_box = BoundingBox { min: first, max: first };
+        for point in points {
+            bounding_box.include(point);
+        }
+
+        return Some(bounding_box);
+    }
+
+    // grow to cover `point`
+    pub fn include (&mut self, point: Point2) {
+        self.min = Point2::new(min(self.min.x, point.x), min(self.min.y, point.y));
+        self.max = Point2::new(max(self.max.x, point.x), max(self.max.y, point.y));
+    }
+
+    pub fn contains (&self, point: Point2) -> bool {
+        return self.min.x <= point.x && point.x <= self.max.x && self.min.y <= point.y && point.y <= self.max.y;
+    }
+
+    // whether there's any point in both, edges included
+    pub fn overlaps (&self, other: &BoundingBox) -> bool {
+        return self.min.x <= other.max.x && other.min.x <= self.max.x && self.min.y <= other.max.y && other.min.y <= self.max.y;
+    }
+
+    // strictly inside, not on the edge
+    pub fn contains_interior (&self, point: Point2) -> bool {
+        return self.min.x < point.x && point.x < self.max.x && self.min.y < point.y && point.y < self.max.y;
+    }
+
+    pub fn width (&self) -> i64 {
+        return self.max.x - self.min.x + 1;
+    }
+
+    pub fn height (&self) -> i64 {
+        return self.max.y - self.min.y + 1;
+    }
+
+    // counted in cells, so a single point has an area of 1
+    pub fn area (&self) -> i64 {
+        return self.width() * self.height();
+    }
+}
@@ -0,0 +1,209 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{error::ParseError, geometry::Point2};
+
+// offsets to the cells sharing an edge, then the ones sharing an edge or a corner
+pub const NEIGHBOURS_4: [(i64, i64); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+pub const NEIGHBOURS_8: [(i64, i64); 8] = [(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1)];
+
+// a rectangle of cells stored row by row
+// coordinates are (x, y) with x counting columns from the left and y counting rows from the top
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn (width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(cell(x, y));
+            }
+        }
+        return Grid { width: width, height: height, cells: cells };
+    }
+
+    // every row has to be as wide as the first
+    pub fn from_rows (rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        let height = rows.len();
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        return Some(Grid { width: width, height: height, cells: rows.into_iter().flatten().collect() });
+    }
+
+    pub fn width (&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height (&self) -> usize {
+        return self.height;
+    }
+
+    pub fn get (&self, x: usize, y: usize) -> Option<&T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        return Some(&self.cells[y * self.width + x]);
+    }
+
+    pub fn get_mut (&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        return Some(&mut self.cells[y * self.width + x]);
+    }
+
+    // off the grid in any direction, including negative coordinates, is None
+    pub fn get_point (&self, point: Point2) -> Option<&T> {
+        if point.x < 0 || point.y < 0 {
+            return None;
+        }
+        return self.get(point.x as usize, point.y as usize);
+    }
+
+    pub fn contains_point (&self, point: Point2) -> bool {
+        return self.get_point(point).is_some();
+    }
+
+    // the cell `offset` away from (x, y), if that's still on the grid
+    pub fn offset (&self, x: usize, y: usize, offset: (i64, i64)) -> Option<(usize, usize)> {
+        let new_x = x as i64 + offset.0;
+        let new_y = y as i64 + offset.1;
+
+        if new_x < 0 || new_y < 0 || new_x >= self.width as i64 || new_y >= self.height as i64 {
+            return None;
+        }
+        return Some((new_x as usize, new_y as usize));
+    }
+
+    pub fn neighbours4 (&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return NEIGHBOURS_4.iter().filter_map(move |offset| self.offset(x, y, *offset));
+    }
+
+    pub fn neighbours8 (&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return NEIGHBOURS_8.iter().filter_map(move |offset| self.offset(x, y, *offset));
+    }
+
+    pub fn coords (&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        return (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)));
+    }
+
+    // every cell along with where it is, row by row
+    pub fn iter (&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        return self.coords().zip(self.cells.iter());
+    }
+
+    pub fn row (&self, y: usize) -> &[T] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn rows (&self) -> impl Iterator<Item = &[T]> {
+        return (0..self.height).map(|y| self.row(y));
+    }
+
+    pub fn column (&self, x: usize) -> impl Iterator<Item = &T> {
+        return self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height);
+    }
+
+    pub fn columns (&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.width).map(|x| self.column(x));
+    }
+
+    pub fn map<U> (&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid { width: self.width, height: self.height, cells: self.cells.iter().map(cell).collect() };
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled (width: usize, height: usize, value: T) -> Grid<T> {
+        return Grid { width: width, height: height, cells: vec![value; width * height] };
+    }
+
+    // swap rows and columns
+    pub fn transpose (&self) -> Grid<T> {
+        return Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone());
+    }
+
+    pub fn rotate_clockwise (&self) -> Grid<T> {
+        return Grid::from_fn(self.height, self.width, |x, y| self[(y, self.height - 1 - x)].clone());
+    }
+
+    pub fn rotate_anticlockwise (&self) -> Grid<T> {
+        return Grid::from_fn(self.height, self.width, |x, y| self[(self.width - 1 - y, x)].clone());
+    }
+}
+
+impl<T> Grid<T> {
+    // one row per line, with `cell` turning each character into a cell or saying what it
+    // expected instead
+    pub fn parse_with (input: &str, cell: impl FnMut((usize, usize), char) -> Result<T, String>) -> Result<Grid<T>, ParseError> {
+        let grid = Grid::parse_lines(input.lines().enumerate().map(|(line_idx, line)| (line_idx + 1, line)), cell)?;
+
+        if grid.height == 0 {
+            return Err(ParseError::end_of_input(input, "a grid"));
+        }
+        return Ok(grid);
+    }
+
+    // for when every cell is parsed the same way, `expected` describes the characters `cell` accepts
+    pub fn parse (input: &str, mut cell: impl FnMut(char) -> Option<T>, expected: &str) -> Result<Grid<T>, ParseError> {
+        return Grid::parse_with(input, |_, c| cell(c).ok_or(expected.to_string()));
+    }
+
+    // for grids embedded in a bigger input, each line comes with its line number for diagnostics
+    // no lines at all gives an empty grid
+    pub fn parse_lines<'a> (lines: impl IntoIterator<Item = (usize, &'a str)>, mut cell: impl FnMut((usize, usize), char) -> Result<T, String>) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, (line_number, line)) in lines.into_iter().enumerate() {
+            let mut row_width = 0;
+
+            for (x, c) in line.chars().enumerate() {
+                // point at the first character past the end of the row rather than the end of the line
+                if let Some(width) = width.filter(|width| x >= *width) {
+                    return Err(ParseError::at_column(line_number, line, x + 1, format!("a row {} wide", width), format!("{:?}", c)));
+                }
+
+                match cell((x, y), c) {
+                    Ok(value) => cells.push(value),
+                    Err(expected) => return Err(ParseError::at_column(line_number, line, x + 1, expected, format!("{:?}", c))),
+                }
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => return Err(ParseError::end_of_line(line_number, line, format!("a row {} wide", width))),
+                Some(_) => {},
+            }
+            height += 1;
+        }
+
+        return Ok(Grid { width: width.unwrap_or(0), height: height, cells: cells });
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index (&self, (x, y): (usize, usize)) -> &T {
+        return self.get(x, y).unwrap_or_else(|| panic!("({}, {}) is outside a {}x{} grid", x, y, self.width, self.height));
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut (&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        return self.get_mut(x, y).unwrap_or_else(|| panic!("({}, {}) is outside a {}x{} grid", x, y, width, height));
+    }
+}
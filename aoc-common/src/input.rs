@@ -0,0 +1,103 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const INPUT_FILENAME: &str = "input.txt";
+pub const EXAMPLE_FILENAME: &str = "example.txt";
+pub const STDIN_ARG: &str = "-";
+pub const EXAMPLE_ARG: &str = "--example";
+
+// where `aoc fetch` keeps downloaded inputs, relative to the workspace unless overridden
+pub const CACHE_DIR_ENV: &str = "AOC_CACHE_DIR";
+pub const DEFAULT_CACHE_DIR: &str = ".cache/inputs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // the day's own input.txt, or the downloaded one if there isn't one
+    Default,
+    // the day's own example.txt
+    Example,
+    Stdin,
+    Path(PathBuf)
+}
+
+impl InputSource {
+    // a path argument, where `-` means stdin
+    pub fn from_arg (arg: &str) -> InputSource {
+        if arg == STDIN_ARG {
+            return InputSource::Stdin;
+        }
+        return InputSource::Path(PathBuf::from(arg));
+    }
+
+    pub fn path (&self, day_dir: &Path) -> Option<PathBuf> {
+        return match self {
+            InputSource::Default => Some(default_input(day_dir)),
+            InputSource::Example => Some(day_dir.join(EXAMPLE_FILENAME)),
+            InputSource::Stdin => None,
+            InputSource::Path(path) => Some(path.clone()),
+        }
+    }
+
+    // what to call the input in diagnostics
+    pub fn name (&self, day_dir: &Path) -> String {
+        return match self.path(day_dir) {
+            Some(path) => path.display().to_string(),
+            None => "<stdin>".to_string(),
+        }
+    }
+
+    // days are run from the workspace root, so the default files are resolved against the
+    // day's own directory rather than the working directory
+    pub fn read (&self, day_dir: &Path) -> io::Result<String> {
+        return match self.path(day_dir) {
+            Some(path) => read(path),
+            None => read_stdin(),
+        }
+    }
+}
+
+// an input.txt in the day's directory wins over a downloaded one
+// if there's neither, the day's input.txt is still the one to complain about
+fn default_input (day_dir: &Path) -> PathBuf {
+    let local = day_dir.join(INPUT_FILENAME);
+    if local.exists() {
+        return local;
+    }
+
+    let day = day_dir.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_prefix("day"))
+        .and_then(|day| day.parse::<u32>().ok());
+
+    if let (Some(day), Some(workspace_root)) = (day, day_dir.parent()) {
+        let cached = cached_input(workspace_root, day);
+        if cached.exists() {
+            return cached;
+        }
+    }
+
+    return local;
+}
+
+pub fn cache_dir (workspace_root: &Path) -> PathBuf {
+    return match std::env::var_os(CACHE_DIR_ENV) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => workspace_root.join(DEFAULT_CACHE_DIR),
+    }
+}
+
+pub fn cached_input (workspace_root: &Path, day: u32) -> PathBuf {
+    return cache_dir(workspace_root).join(format!("day{}.txt", day));
+}
+
+// errors are left for the caller to report, which knows what to call the input
+pub fn read (path: impl AsRef<Path>) -> io::Result<String> {
+    return fs::read_to_string(path);
+}
+
+pub fn read_stdin () -> io::Result<String> {
+    let mut contents = String::new();
+    io::stdin().read_to_string(&mut contents)?;
+    return Ok(contents);
+}
@@ -0,0 +1,181 @@
+use std::fmt::{Display, Formatter, Write};
+
+use crate::answer::Answer;
+
+// just enough JSON to write results out for other tools, objects keep their insertion order
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>)
+}
+
+impl Json {
+    pub fn object () -> Json {
+        return Json::Object(vec![]);
+    }
+
+    // builder style so nested objects read top to bottom
+    pub fn with (mut self, key: &str, value: impl Into<Json>) -> Json {
+        if let Json::Object(fields) = &mut self {
+            fields.push((key.to_string(), value.into()));
+        }
+        return self;
+    }
+}
+
+impl Display for Json {
+    fn fmt (&self, f: &mut Formatter) -> std::fmt::Result {
+        return match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Int(n) => write!(f, "{}", n),
+            // JSON has no NaN or infinity
+            Json::Float(x) if !x.is_finite() => write!(f, "null"),
+            Json::Float(x) => write!(f, "{}", x),
+            Json::Str(s) => write_string(f, s),
+            Json::Array(values) => {
+                f.write_char('[')?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                f.write_char(']')
+            },
+            Json::Object(fields) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_char('}')
+            },
+        }
+    }
+}
+
+fn write_string (f: &mut Formatter, s: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    return f.write_char('"');
+}
+
+impl From<bool> for Json {
+    fn from (value: bool) -> Json {
+        return Json::Bool(value);
+    }
+}
+
+impl From<i64> for Json {
+    fn from (value: i64) -> Json {
+        return Json::Int(value);
+    }
+}
+
+impl From<u32> for Json {
+    fn from (value: u32) -> Json {
+        return Json::Int(value as i64);
+    }
+}
+
+impl From<usize> for Json {
+    fn from (value: usize) -> Json {
+        return Json::Int(value as i64);
+    }
+}
+
+impl From<f64> for Json {
+    fn from (value: f64) -> Json {
+        return Json::Float(value);
+    }
+}
+
+impl From<&str> for Json {
+    fn from (value: &str) -> Json {
+        return Json::Str(value.to_string());
+    }
+}
+
+impl From<String> for Json {
+    fn from (value: String) -> Json {
+        return Json::Str(value);
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from (value: Option<T>) -> Json {
+        return match value {
+            Some(value) => value.into(),
+            None => Json::Null,
+        }
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from (values: Vec<T>) -> Json {
+        return Json::Array(values.into_iter().map(|v| v.into()).collect());
+    }
+}
+
+// unsolved parts come out as null so consumers can tell them apart from a real answer
+impl From<&Answer> for Json {
+    fn from (answer: &Answer) -> Json {
+        return match answer {
+            Answer::Number(n) => Json::Int(*n),
+            Answer::Text(s) => Json::Str(s.clone()),
+            Answer::Unsolved => Json::Null,
+        }
+    }
+}
+
+impl From<Answer> for Json {
+    fn from (answer: Answer) -> Json {
+        return Json::from(&answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Json;
+    use crate::answer::Answer;
+
+    #[test]
+    fn strings_are_escaped () {
+        let json = Json::from("say \"hi\"\\ \n\r\t\u{1}\u{1f} é");
+        assert_eq!(json.to_string(), r#""say \"hi\"\\ \n\r\t\u0001\u001f é""#);
+
+        // keys go through the same escaping
+        assert_eq!(Json::object().with("a\"b", 1_i64).to_string(), r#"{"a\"b":1}"#);
+    }
+
+    #[test]
+    fn missing_values_are_null () {
+        assert_eq!(Json::from(None::<i64>), Json::Null);
+        assert_eq!(Json::from(Some(3_i64)), Json::Int(3));
+        assert_eq!(Json::from(Answer::Unsolved), Json::Null);
+        assert_eq!(Json::from(Answer::Text("x".to_string())), Json::Str("x".to_string()));
+        assert_eq!(Json::from(f64::NAN).to_string(), "null");
+
+        let json = Json::object().with("part1", Answer::Number(-4)).with("part2", Answer::Unsolved).with("runs", vec![Some(1_usize), None]);
+        assert_eq!(json.to_string(), r#"{"part1":-4,"part2":null,"runs":[1,null]}"#);
+    }
+}
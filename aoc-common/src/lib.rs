@@ -0,0 +1,23 @@
+// shared plumbing for the day crates
+// everything that isn't solving the puzzle lives here
+
+pub mod answer;
+pub mod args;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod memory;
+pub mod parse;
+pub mod random;
+pub mod report;
+pub mod solution;
+pub mod stats;
+pub mod testing;
+pub mod timing;
+
+pub use answer::Answer;
+pub use error::ParseError;
+pub use solution::Solution;
@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// how much to say on stderr, each level includes everything above it
+// answers never go through here, they always go to stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    // something went wrong and there's no answer
+    Error,
+    // timings and stats, shown by default
+    Info,
+    // a summary of what each part is doing, -v
+    Debug,
+    // every step of the working, -vv
+    Trace
+}
+
+pub const DEFAULT_LEVEL: Level = Level::Info;
+pub const VERBOSE_ARG: &str = "-v";
+pub const VERY_VERBOSE_ARG: &str = "-vv";
+
+static LEVEL: AtomicU8 = AtomicU8::new(DEFAULT_LEVEL as u8);
+
+impl Level {
+    fn from_u8 (level: u8) -> Level {
+        return match level {
+            0 => Level::Error,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    // one step more verbose, stopping at trace
+    pub fn more_verbose (self) -> Level {
+        return Level::from_u8(self as u8 + 1);
+    }
+}
+
+pub fn set_level (level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level () -> Level {
+    return Level::from_u8(LEVEL.load(Ordering::Relaxed));
+}
+
+pub fn enabled (level: Level) -> bool {
+    return level <= self::level();
+}
+
+// `-v` and `-vv` can be given more than once, each `v` is one more level
+pub fn verbosity_arg (arg: &str, level: Level) -> Option<Level> {
+    return match arg {
+        VERBOSE_ARG => Some(level.more_verbose()),
+        VERY_VERBOSE_ARG => Some(level.more_verbose().more_verbose()),
+        _ => None,
+    }
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Error) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Info) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
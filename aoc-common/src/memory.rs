@@ -0,0 +1,163 @@
+// an opt-in count of heap allocations, so how much memory each phase used can be reported
+// alongside how long it took. binaries install the allocator with `count_allocations!()`,
+// leaving anything that embeds the solvers free to use its own, and it only counts once
+// `enable` has been called, so runs that don't ask for it only pay for checking a flag
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub const MEMORY_ARG: &str = "--memory";
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+// what one phase of a run allocated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Usage {
+    // the most held at once, on top of whatever was already held when the phase began
+    pub peak_bytes: usize,
+    // reallocating counts as another one, as it often means copying everything across
+    pub allocations: usize
+}
+
+// the usage of each phase of a single run, laid out like `Timings`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Memory {
+    pub parse: Usage,
+    pub part1: Option<Usage>,
+    pub part2: Option<Usage>
+}
+
+impl Memory {
+    pub fn part (&self, part: u32) -> Option<Usage> {
+        return match part {
+            1 => self.part1,
+            _ => self.part2,
+        }
+    }
+
+    pub fn set_part (&mut self, part: u32, usage: Usage) {
+        match part {
+            1 => self.part1 = Some(usage),
+            _ => self.part2 = Some(usage),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Counter {
+    // bytes held by this thread, which goes negative if it frees something another thread allocated
+    current: isize,
+    peak: isize,
+    // where `current` was at the last reset
+    baseline: isize,
+    allocations: usize
+}
+
+thread_local! {
+    // counted per thread like `stats`, so days running side by side don't count each other's allocations
+    // a plain Cell with nothing to drop, so using it never allocates
+    static COUNTER: Cell<Counter> = const { Cell::new(Counter { current: 0, peak: 0, baseline: 0, allocations: 0 }) };
+}
+
+// wraps the system allocator, see `count_allocations!`
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc (&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(layout.size() as isize, 1);
+        }
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed (&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(layout.size() as isize, 1);
+        }
+        return ptr;
+    }
+
+    unsafe fn dealloc (&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(-(layout.size() as isize), 0);
+    }
+
+    unsafe fn realloc (&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count(new_size as isize - layout.size() as isize, 1);
+        }
+        return new_ptr;
+    }
+}
+
+// makes the counting allocator the global one, once in each binary that wants to report memory
+// without it `--memory` still runs, but everything comes out as 0
+#[macro_export]
+macro_rules! count_allocations {
+    () => {
+        #[global_allocator]
+        static ALLOCATOR: $crate::memory::CountingAllocator = $crate::memory::CountingAllocator;
+    };
+}
+
+fn count (bytes: isize, allocations: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    // a thread that's shutting down may have already lost its counter, its frees don't matter
+    let _ = COUNTER.try_with(|counter| {
+        let mut updated = counter.get();
+        updated.current += bytes;
+        updated.peak = updated.peak.max(updated.current);
+        updated.allocations += allocations;
+        counter.set(updated);
+    });
+}
+
+// start counting, for every thread from here on
+pub fn enable () {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled () -> bool {
+    return ENABLED.load(Ordering::Relaxed);
+}
+
+// start a new phase on this thread
+pub fn reset () {
+    COUNTER.with(|counter| {
+        let mut updated = counter.get();
+        updated.baseline = updated.current;
+        updated.peak = updated.current;
+        updated.allocations = 0;
+        counter.set(updated);
+    });
+}
+
+// what this thread has allocated since the last reset
+pub fn usage () -> Usage {
+    let counter = COUNTER.with(|counter| counter.get());
+    return Usage { peak_bytes: (counter.peak - counter.baseline).max(0) as usize, allocations: counter.allocations };
+}
+
+// binary units, to one decimal place once it's past bytes
+pub fn format_bytes (bytes: usize) -> String {
+    let units = ["KiB", "MiB", "GiB"];
+
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    return format!("{:.1}{}", size, units[unit]);
+}
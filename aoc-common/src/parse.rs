@@ -0,0 +1,167 @@
+// small building blocks for the shapes inputs keep coming in: lines, blank-line separated
+// sections, separated lists, `a-b` ranges, `name: items` lines and bracketed groups
+// everything works on spans, so whatever goes wrong can be pointed at in the original line
+
+use std::slice::SliceIndex;
+use std::str::FromStr;
+
+use crate::error::{column_of, ParseError};
+
+// some text from the input along with the line it came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub line_number: usize,
+    // the whole line, `text` is always a slice of it
+    pub line: &'a str,
+    pub text: &'a str
+}
+
+impl<'a> Span<'a> {
+    // all of a line, line numbers are 1-based
+    pub fn line (line_number: usize, line: &'a str) -> Span<'a> {
+        return Span { line_number: line_number, line: line, text: line };
+    }
+
+    // `text` has to be a slice of this span's text
+    fn sub (&self, text: &'a str) -> Span<'a> {
+        return Span { line_number: self.line_number, line: self.line, text: text };
+    }
+
+    pub fn is_empty (&self) -> bool {
+        return self.text.is_empty();
+    }
+
+    // part of this span, by byte offsets into its text
+    pub fn slice<R: SliceIndex<str, Output = str>> (&self, range: R) -> Span<'a> {
+        return self.sub(&self.text[range]);
+    }
+
+    pub fn trim (&self) -> Span<'a> {
+        return self.sub(self.text.trim());
+    }
+
+    pub fn trim_start (&self) -> Span<'a> {
+        return self.sub(self.text.trim_start());
+    }
+
+    // something other than `expected` was found here
+    pub fn error (&self, expected: impl Into<String>) -> ParseError {
+        return ParseError::at_token(self.line_number, self.line, self.text, expected);
+    }
+
+    // `expected` should have come straight after this span
+    pub fn error_after (&self, expected: impl Into<String>) -> ParseError {
+        let end = column_of(self.line, self.text) + self.text.chars().count();
+
+        return match self.line.chars().nth(end - 1) {
+            Some(c) => ParseError::at_column(self.line_number, self.line, end, expected, format!("{:?}", c)),
+            None => ParseError::end_of_line(self.line_number, self.line, expected),
+        }
+    }
+
+    // the whole span as a T, `expected` describes what a T looks like
+    pub fn parse<T: FromStr> (&self, expected: &str) -> Result<T, ParseError> {
+        return self.text.parse::<T>().map_err(|_| self.error(expected));
+    }
+
+    pub fn split (&self, separator: &str) -> Vec<Span<'a>> {
+        return self.text.split(separator).map(|text| self.sub(text)).collect();
+    }
+
+    // either side of the first `separator`, which has to be there
+    pub fn split_once (&self, separator: &str, expected: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        return match self.text.split_once(separator) {
+            Some((before, after)) => Ok((self.sub(before), self.sub(after))),
+            None => Err(self.error_after(expected)),
+        }
+    }
+
+    // the first character and everything after it, None if there's nothing here
+    pub fn split_first (&self) -> Option<(char, Span<'a>)> {
+        let c = self.text.chars().next()?;
+        return Some((c, self.sub(&self.text[c.len_utf8()..])));
+    }
+
+    pub fn words (&self) -> Vec<Span<'a>> {
+        return self.text.split_whitespace().map(|text| self.sub(text)).collect();
+    }
+
+    // each character along with a span covering just it
+    pub fn chars (&self) -> Vec<(Span<'a>, char)> {
+        return self.text.char_indices().map(|(idx, c)| (self.sub(&self.text[idx..(idx + c.len_utf8())]), c)).collect();
+    }
+
+    // every item between `separator`s, each parsed by `item`
+    pub fn separated<T> (&self, separator: &str, item: impl FnMut(Span<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+        return self.split(separator).into_iter().map(item).collect();
+    }
+
+    // every item between `separator`s as a T, `expected` describes a single item
+    pub fn list<T: FromStr> (&self, separator: &str, expected: &str) -> Result<Vec<T>, ParseError> {
+        return self.separated(separator, |item| item.parse::<T>(expected));
+    }
+
+    // exactly N items between `separator`s, like the 3 numbers in 1,2,3
+    pub fn tuple<T: FromStr, const N: usize> (&self, separator: &str, expected: &str) -> Result<[T; N], ParseError> {
+        let items = self.split(separator);
+
+        if let Some(extra) = items.get(N) {
+            return Err(extra.error(format!("only {} values", N)));
+        }
+        if items.len() < N {
+            return Err(self.error_after(format!("{} values separated by {:?}", N, separator)));
+        }
+
+        let values = items.iter().map(|item| item.parse::<T>(expected)).collect::<Result<Vec<T>, ParseError>>()?;
+        return Ok(values.try_into().unwrap_or_else(|_| unreachable!()));
+    }
+
+    // a range like 3-5, both ends included
+    pub fn range<T: FromStr> (&self, expected: &str) -> Result<(T, T), ParseError> {
+        let (start, end) = self.split_once("-", expected)?;
+        return Ok((start.parse::<T>("a number")?, end.parse::<T>("a number")?));
+    }
+
+    // what's between `open` and `close`, which have to be the first and last characters
+    pub fn delimited (&self, open: char, close: char, expected: &str) -> Result<Span<'a>, ParseError> {
+        let inside = self.text.strip_prefix(open).and_then(|text| text.strip_suffix(close));
+
+        return match inside {
+            Some(inside) => Ok(self.sub(inside)),
+            None => Err(self.error(expected)),
+        }
+    }
+}
+
+pub fn lines (input: &str) -> Vec<Span<'_>> {
+    return input.lines().enumerate().map(|(line_idx, line)| Span::line(line_idx + 1, line)).collect();
+}
+
+// each line parsed by `line`, stopping at the first one that doesn't parse
+pub fn each_line<T> (input: &str, line: impl FnMut(Span) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    return lines(input).into_iter().map(line).collect();
+}
+
+// runs of lines separated by blank ones, any number of blank lines in a row count as one break
+pub fn sections (input: &str) -> Vec<Vec<Span<'_>>> {
+    let mut sections = vec![];
+    let mut section = vec![];
+
+    for line in lines(input) {
+        if !line.is_empty() {
+            section.push(line);
+            continue;
+        }
+
+        if !section.is_empty() {
+            sections.push(section);
+            section = vec![];
+        }
+    }
+
+    if !section.is_empty() {
+        sections.push(section);
+    }
+
+    return sections;
+}
@@ -0,0 +1,66 @@
+// seedable random numbers for the input generators, so a seed always gives back the same input
+// splitmix64 isn't suitable for anything that matters, but it's plenty for making up puzzles
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// makes an input of roughly `size` items, whatever an item is for that day
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new (seed: u64) -> Rng {
+        return Rng { state: seed };
+    }
+
+    // for when any seed will do, it still needs reporting so the input can be made again
+    pub fn seed_from_time () -> u64 {
+        let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        return since_epoch.as_nanos() as u64;
+    }
+
+    pub fn next_u64 (&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        return z ^ (z >> 31);
+    }
+
+    // somewhere in 0..n, n has to be above 0
+    pub fn below (&mut self, n: u64) -> u64 {
+        assert!(n > 0, "can't pick a number below 0");
+        // scaling instead of taking the remainder keeps the bias too small to matter
+        return ((self.next_u64() as u128 * n as u128) >> 64) as u64;
+    }
+
+    // somewhere in lo..=hi
+    pub fn range (&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "empty range {}..={}", lo, hi);
+        return lo + self.below((hi - lo) as u64 + 1) as i64;
+    }
+
+    // an index into something `len` long
+    pub fn index (&mut self, len: usize) -> usize {
+        return self.below(len as u64) as usize;
+    }
+
+    // true with the given probability
+    pub fn chance (&mut self, probability: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64;
+        return unit < probability;
+    }
+
+    pub fn choose<'a, T> (&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.index(items.len())];
+    }
+
+    pub fn shuffle<T> (&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt::Display;
+use std::io;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use crate::{error, info, error::ParseError, json::Json, memory::{format_bytes, Memory, Usage}, solution::Run, stats::Stat, timing::{format_duration, BenchStats, Stats, Timings}};
+
+pub fn answer (part: u32, value: impl Display) {
+    println!("Part {}: {}", part, value);
+}
+
+// timings are diagnostics, so they stay out of the way of the answers on stdout
+pub fn timings (timings: &Timings) {
+    info!("Input parsed in {}", format_duration(timings.parse));
+    for part in [1, 2] {
+        if let Some(elapsed) = timings.part(part) {
+            info!("Part {} ran in {}", part, format_duration(elapsed));
+        }
+    }
+    info!("Total {}", format_duration(timings.total()));
+}
+
+pub fn memory (memory: &Memory) {
+    info!("Input parsed using {} peak in {} allocations", format_bytes(memory.parse.peak_bytes), memory.parse.allocations);
+    for part in [1, 2] {
+        if let Some(usage) = memory.part(part) {
+            info!("Part {} used {} peak in {} allocations", part, format_bytes(usage.peak_bytes), usage.allocations);
+        }
+    }
+}
+
+pub fn stats (stats: &[(u32, Vec<(&'static str, Stat)>)]) {
+    for (part, part_stats) in stats {
+        for (name, stat) in part_stats {
+            match stat {
+                Stat::Value(value) => info!("Part {} {}: {}", part, name, value),
+                Stat::List(values) => {
+                    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                    info!("Part {} {}: [{}]", part, name, values.join(", "));
+                },
+            }
+        }
+    }
+}
+
+// parse errors always get the full diagnostic on stderr, whatever the output format
+pub fn exit_code<T> (result: &Result<T, ParseError>, input_name: &str) -> ExitCode {
+    if let Err(err) = result {
+        error!("{}", err.clone().in_file(input_name));
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+// an input that couldn't be read, so the day never got to run
+pub fn read_error (input_name: &str, err: &io::Error) -> ExitCode {
+    error!("unable to read {}: {}", input_name, err);
+    return ExitCode::FAILURE;
+}
+
+// everything from one run of a day, or why it couldn't be run
+pub fn run_json (day: u32, input_name: &str, result: &Result<Run, ParseError>) -> Json {
+    let json = Json::object().with("day", day).with("input", input_name);
+
+    let run = match result {
+        Ok(run) => run,
+        Err(err) => return json.with("error", error_json(&err.clone().in_file(input_name))),
+    };
+
+    let mut answers = Json::object();
+    let mut timings = Json::object().with("parse", micros(run.timings.parse));
+    let mut stats = Json::object();
+
+    for (part, answer) in run.answers.iter() {
+        answers = answers.with(&part_key(*part), answer);
+        timings = timings.with(&part_key(*part), run.timings.part(*part).map(micros));
+    }
+    timings = timings.with("total", micros(run.timings.total()));
+
+    for (part, part_stats) in run.stats.iter() {
+        if !part_stats.is_empty() {
+            stats = stats.with(&part_key(*part), stats_json(part_stats));
+        }
+    }
+
+    let json = json.with("answers", answers).with("timings_us", timings);
+
+    if let Some(memory) = &run.memory {
+        let mut phases = Json::object().with("parse", usage_json(&memory.parse));
+        for (part, _) in run.answers.iter() {
+            phases = phases.with(&part_key(*part), memory.part(*part).map(|usage| usage_json(&usage)));
+        }
+        return json.with("memory", phases).with("stats", stats);
+    }
+    return json.with("stats", stats);
+}
+
+// a day that panicked instead of giving answers
+pub fn panic_json (day: u32, input_name: &str, message: &str) -> Json {
+    return Json::object().with("day", day).with("input", input_name).with("error", Json::object().with("panic", message));
+}
+
+pub fn read_error_json (day: u32, input_name: &str, err: &io::Error) -> Json {
+    return Json::object().with("day", day).with("input", input_name).with("error", Json::object().with("read", err.to_string()));
+}
+
+pub fn bench_json (day: u32, input_name: &str, result: &Result<BenchStats, ParseError>) -> Json {
+    let json = Json::object().with("day", day).with("input", input_name);
+
+    let stats = match result {
+        Ok(stats) => stats,
+        Err(err) => return json.with("error", error_json(&err.clone().in_file(input_name))),
+    };
+
+    let mut phases = Json::object();
+    for (phase, phase_stats) in [("parse", Some(stats.parse)), ("part1", stats.part1), ("part2", stats.part2)] {
+        if let Some(phase_stats) = phase_stats {
+            phases = phases.with(phase, bench_stats_json(&phase_stats));
+        }
+    }
+
+    return json.with("runs", stats.runs).with("timings_us", phases);
+}
+
+pub fn error_json (err: &ParseError) -> Json {
+    return Json::object()
+        .with("file", err.file.clone())
+        .with("line", err.line)
+        .with("column", err.column)
+        .with("expected", err.expected.clone())
+        .with("found", err.found.clone())
+        .with("source_line", err.source_line.clone());
+}
+
+fn stats_json (stats: &[(&'static str, Stat)]) -> Json {
+    let mut json = Json::object();
+    for (name, stat) in stats {
+        json = match stat {
+            Stat::Value(value) => json.with(name, value),
+            Stat::List(values) => json.with(name, values.clone()),
+        };
+    }
+    return json;
+}
+
+fn usage_json (usage: &Usage) -> Json {
+    return Json::object().with("peak_bytes", usage.peak_bytes).with("allocations", usage.allocations);
+}
+
+fn bench_stats_json (stats: &Stats) -> Json {
+    return Json::object()
+        .with("min", micros(stats.min))
+        .with("median", micros(stats.median))
+        .with("max", micros(stats.max));
+}
+
+fn part_key (part: u32) -> String {
+    return format!("part{}", part);
+}
+
+// whole microseconds keep the numbers exact, which floating point milliseconds wouldn't
+fn micros (duration: Duration) -> Json {
+    return Json::Int(duration.as_micros() as i64);
+}
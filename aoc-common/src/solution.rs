@@ -0,0 +1,150 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+use crate::{answer::Answer, args::{DayArgs, OutputFormat}, error::ParseError, log, memory::{self, Memory}, report, stats::{self, Stat}, timing::{self, BenchStats, Timer, Timings}};
+
+pub const PARTS: [u32; 2] = [1, 2];
+
+// every day is solved by parsing the raw input once and handing the result to each part
+pub trait Solution {
+    const DAY: u32;
+    type Input;
+
+    fn parse (input: &str) -> Result<Self::Input, ParseError>;
+    fn part1 (input: &Self::Input) -> Answer;
+    fn part2 (input: &Self::Input) -> Answer;
+}
+
+// the answers from a single run along with how long each phase took
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub answers: Vec<(u32, Answer)>,
+    pub timings: Timings,
+    // only counted when asked for with `--memory`
+    pub memory: Option<Memory>,
+    // whatever each part recorded through `stats`
+    pub stats: Vec<(u32, Vec<(&'static str, Stat)>)>
+}
+
+impl Run {
+    pub fn answer (&self, part: u32) -> Answer {
+        return self.answers.iter()
+            .find(|(p, _)| *p == part)
+            .map(|(_, answer)| answer.clone())
+            .unwrap_or(Answer::Unsolved);
+    }
+}
+
+// type-erased entry point so the runner can keep every day in one table
+pub type Runner = fn(&str, &[u32]) -> Result<Run, ParseError>;
+
+pub fn run<S: Solution> (input: &str, parts: &[u32]) -> Result<Run, ParseError> {
+    let mut timings = Timings::default();
+    let mut memory = Memory::default();
+
+    // throw away anything left over from a run that panicked part way through
+    stats::take();
+
+    memory::reset();
+    let timer = Timer::start();
+    let parsed = S::parse(input)?;
+    timings.parse = timer.elapsed();
+    memory.parse = memory::usage();
+
+    let mut answers = vec![];
+    let mut part_stats = vec![];
+
+    for part in parts {
+        memory::reset();
+        let timer = Timer::start();
+        let answer = match part {
+            1 => S::part1(&parsed),
+            2 => S::part2(&parsed),
+            _ => panic!("day {} has no part {}", S::DAY, part),
+        };
+        timings.set_part(*part, timer.elapsed());
+        memory.set_part(*part, memory::usage());
+        answers.push((*part, answer));
+        part_stats.push((*part, stats::take()));
+    }
+
+    let memory = if memory::is_enabled() { Some(memory) } else { None };
+    return Ok(Run { answers: answers, timings: timings, memory: memory, stats: part_stats });
+}
+
+// runs the same input `runs` times, stopping at the first parse error
+pub fn bench (runner: Runner, input: &str, parts: &[u32], runs: usize) -> Result<BenchStats, ParseError> {
+    let mut timings = vec![];
+
+    for _ in 0..runs {
+        timings.push(runner(input, parts)?.timings);
+    }
+
+    return Ok(BenchStats::from_runs(&timings).unwrap());
+}
+
+// parse and solve a single part straight from the raw input
+pub fn solve_part1<S: Solution> (input: &str) -> Result<Answer, ParseError> {
+    return Ok(S::part1(&S::parse(input)?));
+}
+
+pub fn solve_part2<S: Solution> (input: &str) -> Result<Answer, ParseError> {
+    return Ok(S::part2(&S::parse(input)?));
+}
+
+pub const USAGE: &str = "usage: dayN [--example | <path> | -] [--bench <runs> | --memory] [--format <human|json>] [-v | -vv]";
+
+// everything a day's binary needs to do, given the directory its input lives in
+pub fn main<S: Solution> (day_dir: &str) -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let day_args = match DayArgs::parse(&args) {
+        Ok(day_args) => day_args,
+        Err(err) => {
+            eprintln!("{}", err);
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    log::set_level(day_args.log_level);
+    if day_args.memory {
+        memory::enable();
+    }
+
+    let day_dir = Path::new(day_dir);
+    let input_name = day_args.input.name(day_dir);
+    let input = match day_args.input.read(day_dir) {
+        Ok(input) => input,
+        Err(err) => return report::read_error(&input_name, &err),
+    };
+
+    if let Some(runs) = day_args.bench {
+        let result = bench(run::<S>, &input, &PARTS, runs);
+        match (&result, day_args.format) {
+            (_, OutputFormat::Json) => println!("{}", report::bench_json(S::DAY, &input_name, &result)),
+            (Ok(stats), OutputFormat::Human) => timing::print_bench(&format!("Day {}", S::DAY), stats),
+            (Err(_), OutputFormat::Human) => {},
+        }
+        return report::exit_code(&result, &input_name);
+    }
+
+    let result = run::<S>(&input, &PARTS);
+    match (&result, day_args.format) {
+        (_, OutputFormat::Json) => println!("{}", report::run_json(S::DAY, &input_name, &result)),
+        (Ok(result), OutputFormat::Human) => {
+            for (part, answer) in result.answers.iter() {
+                if *answer != Answer::Unsolved {
+                    report::answer(*part, answer);
+                }
+            }
+            report::stats(&result.stats);
+            report::timings(&result.timings);
+            if let Some(memory) = &result.memory {
+                report::memory(memory);
+            }
+        },
+        (Err(_), OutputFormat::Human) => {},
+    }
+
+    return report::exit_code(&result, &input_name);
+}
@@ -0,0 +1,45 @@
+use std::cell::RefCell;
+
+use crate::answer::Answer;
+
+// extra numbers a day wants to report alongside its answer, e.g. how many ranges were left after
+// condensing them. parts record into a per-thread list which the runner takes after each part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stat {
+    Value(Answer),
+    List(Vec<Answer>)
+}
+
+thread_local! {
+    static RECORDED: RefCell<Vec<(&'static str, Stat)>> = const { RefCell::new(vec![]) };
+}
+
+// set a single named value, replacing anything recorded under the same name
+pub fn record (name: &'static str, value: impl Into<Answer>) {
+    let value = Stat::Value(value.into());
+    RECORDED.with(|recorded| {
+        let mut recorded = recorded.borrow_mut();
+        match recorded.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, stat)) => *stat = value,
+            None => recorded.push((name, value)),
+        }
+    });
+}
+
+// add a value to a named list, e.g. one entry per machine
+pub fn push (name: &'static str, value: impl Into<Answer>) {
+    let value = value.into();
+    RECORDED.with(|recorded| {
+        let mut recorded = recorded.borrow_mut();
+        match recorded.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, Stat::List(values))) => values.push(value),
+            Some((_, stat)) => *stat = Stat::List(vec![value]),
+            None => recorded.push((name, Stat::List(vec![value]))),
+        }
+    });
+}
+
+// everything recorded since the last call, in the order it was first recorded
+pub fn take () -> Vec<(&'static str, Stat)> {
+    return RECORDED.with(|recorded| recorded.take());
+}
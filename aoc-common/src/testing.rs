@@ -0,0 +1,144 @@
+// runs a day against every example file in its directory and compares the answers with the
+// ones recorded next to it, e.g. example_2.txt is checked against example_2.answers
+//
+// an answers file has one `partN: answer` line per part it checks, parts that aren't listed
+// aren't checked, and lines starting with # are comments
+//
+// it also runs a day against inputs from its generator, where there aren't any answers to compare
+// with, only that the input parses, neither part panics and the answers relate the way they should
+
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+use crate::random::{Generator, Rng};
+use crate::solution::Solution;
+
+pub const ANSWERS_EXTENSION: &str = "answers";
+
+// how many inputs to try at each size
+pub const GENERATED_SEEDS: u64 = 10;
+
+pub fn check_examples<S: Solution> (day_dir: &str) {
+    let examples = example_files(Path::new(day_dir));
+    assert!(!examples.is_empty(), "no example files in {}", day_dir);
+
+    let mut failures = vec![];
+
+    for example in examples {
+        let answers_path = example.with_extension(ANSWERS_EXTENSION);
+        let expected = match fs::read_to_string(&answers_path) {
+            Ok(contents) => parse_answers(&answers_path, &contents),
+            Err(_) => {
+                failures.push(format!("{}: no recorded answers, expected {}", example.display(), answers_path.display()));
+                continue;
+            }
+        };
+
+        let input = match crate::input::read(&example) {
+            Ok(input) => input,
+            Err(err) => {
+                failures.push(format!("{}: unable to read: {}", example.display(), err));
+                continue;
+            }
+        };
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                failures.push(err.in_file(example.display().to_string()).to_string());
+                continue;
+            }
+        };
+
+        for (part, expected_answer) in expected {
+            let answer = match part {
+                1 => S::part1(&parsed),
+                _ => S::part2(&parsed),
+            };
+
+            if answer.to_string() != expected_answer {
+                failures.push(format!("{} part {}: expected {}, got {}", example.display(), part, expected_answer, answer));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "day {} examples failed:\n{}", S::DAY, failures.join("\n"));
+}
+
+// failures name the size and seed, `aoc gen --day N --size S --seed X` makes the same input again
+pub fn check_generated<S: Solution> (generate: Generator, sizes: &[usize], property: fn(&Answer, &Answer) -> bool) {
+    let mut failures = vec![];
+
+    for size in sizes {
+        for seed in 0..GENERATED_SEEDS {
+            let input = generate(&mut Rng::new(seed), *size);
+            let parsed = match S::parse(&input) {
+                Ok(parsed) => parsed,
+                Err(err) => {
+                    failures.push(format!("size {} seed {}: {}", size, seed, err));
+                    continue;
+                }
+            };
+
+            let answers = panic::catch_unwind(AssertUnwindSafe(|| (S::part1(&parsed), S::part2(&parsed))));
+            match answers {
+                Ok((part1, part2)) if !property(&part1, &part2) => {
+                    failures.push(format!("size {} seed {}: answers {} and {} don't hold up", size, seed, part1, part2));
+                },
+                Ok(_) => {},
+                Err(_) => failures.push(format!("size {} seed {}: panicked", size, seed)),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "day {} generated inputs failed:\n{}", S::DAY, failures.join("\n"));
+}
+
+// for days with nothing to say about how the two answers relate
+pub fn any_answers (_part1: &Answer, _part2: &Answer) -> bool {
+    return true;
+}
+
+// example.txt, example_2.txt etc, in name order
+pub fn example_files (day_dir: &Path) -> Vec<PathBuf> {
+    let mut examples = vec![];
+
+    let entries = match fs::read_dir(day_dir) {
+        Ok(entries) => entries,
+        Err(err) => panic!("unable to read {}: {}", day_dir.display(), err),
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if name.starts_with("example") && name.ends_with(".txt") {
+            examples.push(path);
+        }
+    }
+
+    examples.sort();
+    return examples;
+}
+
+pub fn parse_answers (path: &Path, contents: &str) -> Vec<(u32, String)> {
+    let mut answers = vec![];
+
+    for (line_idx, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let part = match line.split_once(':') {
+            Some(("part1", answer)) => (1, answer.trim().to_string()),
+            Some(("part2", answer)) => (2, answer.trim().to_string()),
+            _ => panic!("{}:{}: expected `part1: <answer>` or `part2: <answer>`, found {:?}", path.display(), line_idx + 1, line),
+        };
+        answers.push(part);
+    }
+
+    return answers;
+}
+
@@ -0,0 +1,120 @@
+use std::time::{Duration, Instant};
+
+use crate::debug;
+
+pub struct Timer {
+    start: Instant
+}
+
+impl Timer {
+    pub fn start () -> Timer {
+        return Timer { start: Instant::now() }
+    }
+
+    pub fn elapsed (&self) -> Duration {
+        return self.start.elapsed();
+    }
+
+    pub fn report (&self, label: &str) {
+        debug!("{} in {}", label, format_duration(self.elapsed()));
+    }
+}
+
+// how long each phase of a single run took
+// parts that weren't run have no timing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>
+}
+
+impl Timings {
+    pub fn part (&self, part: u32) -> Option<Duration> {
+        return match part {
+            1 => self.part1,
+            _ => self.part2,
+        }
+    }
+
+    pub fn set_part (&mut self, part: u32, elapsed: Duration) {
+        match part {
+            1 => self.part1 = Some(elapsed),
+            _ => self.part2 = Some(elapsed),
+        }
+    }
+
+    pub fn total (&self) -> Duration {
+        return self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration
+}
+
+impl Stats {
+    pub fn from_samples (samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n_samples = sorted.len();
+        let median = if n_samples % 2 == 1 {
+            sorted[n_samples / 2]
+        } else {
+            (sorted[n_samples / 2 - 1] + sorted[n_samples / 2]) / 2
+        };
+
+        return Some(Stats { min: sorted[0], median: median, max: sorted[n_samples - 1] });
+    }
+}
+
+// min/median/max of each phase over repeated runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>
+}
+
+impl BenchStats {
+    pub fn from_runs (runs: &[Timings]) -> Option<BenchStats> {
+        let parse = Stats::from_samples(&runs.iter().map(|t| t.parse).collect::<Vec<_>>())?;
+        let part1 = Stats::from_samples(&runs.iter().filter_map(|t| t.part1).collect::<Vec<_>>());
+        let part2 = Stats::from_samples(&runs.iter().filter_map(|t| t.part2).collect::<Vec<_>>());
+
+        return Some(BenchStats { runs: runs.len(), parse: parse, part1: part1, part2: part2 });
+    }
+
+    pub fn phases (&self) -> Vec<(&'static str, Stats)> {
+        let mut phases = vec![("parse", self.parse)];
+        if let Some(part1) = self.part1 {
+            phases.push(("part 1", part1));
+        }
+        if let Some(part2) = self.part2 {
+            phases.push(("part 2", part2));
+        }
+        return phases;
+    }
+}
+
+// milliseconds to the microsecond, so every day reads the same regardless of how long it takes
+pub fn format_duration (duration: Duration) -> String {
+    return format!("{:.3}ms", duration.as_secs_f64() * 1000.0);
+}
+
+pub fn print_bench (label: &str, stats: &BenchStats) {
+    println!("{} ({} runs)", label, stats.runs);
+    println!("{:<8} | {:>14} | {:>14} | {:>14}", "phase", "min", "median", "max");
+    for (phase, phase_stats) in stats.phases() {
+        println!("{:<8} | {:>14} | {:>14} | {:>14}", phase, format_duration(phase_stats.min), format_duration(phase_stats.median), format_duration(phase_stats.max));
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_common::geometry::{BoundingBox, Direction, Point2, Point3};
+
+#[test]
+fn turning_round () {
+    for direction in Direction::ALL {
+        assert_eq!(direction.rotate_right().rotate_left(), direction);
+        assert_eq!(direction.rotate_right().rotate_right(), direction.opposite());
+        assert_eq!(direction.rotate_left().rotate_left(), direction.opposite());
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_eq!(direction.offset() + direction.opposite().offset(), Point2::ORIGIN);
+    }
+
+    assert_eq!(Direction::North.rotate_right(), Direction::East);
+    assert_eq!(Direction::West.rotate_right(), Direction::North);
+    assert_eq!(Direction::North.rotate_left(), Direction::West);
+    assert_eq!(Direction::East.opposite(), Direction::West);
+
+    // y grows downwards, so north is up the screen
+    assert_eq!(Point2::new(3, 3).step(Direction::North), Point2::new(3, 2));
+    assert_eq!(Point2::new(3, 3).step(Direction::East), Point2::new(4, 3));
+}
+
+#[test]
+fn points_as_vectors () {
+    let a = Point2::new(2, -3);
+    let b = Point2::new(-5, 7);
+
+    assert_eq!(a + b, Point2::new(-3, 4));
+    assert_eq!(a - b, Point2::new(7, -10));
+    assert_eq!(b - a, -(a - b));
+    assert_eq!(a * -2, Point2::new(-4, 6));
+    assert_eq!((b - a).signum(), Point2::new(-1, 1));
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, b);
+
+    assert_eq!(a.manhattan(b), 17);
+    assert_eq!(a.chebyshev(b), 10);
+    assert_eq!(a.squared_euclidean(b), 149);
+
+    let p = Point3::new(1, -2, 3);
+    let q = Point3::new(-4, 0, 3);
+    assert_eq!(p - q, Point3::new(5, -2, 0));
+    assert_eq!(-(p + q), Point3::new(3, 2, -6));
+    assert_eq!((q - p).signum(), Point3::new(-1, 1, 0));
+    assert_eq!(p.manhattan(q), 7);
+    assert_eq!(p.chebyshev(q), 5);
+}
+
+#[test]
+fn bounding_boxes_count_cells () {
+    // corners the wrong way round still make the same box
+    let bounding_box = BoundingBox::from_corners(Point2::new(4, 1), Point2::new(2, 5));
+    assert_eq!(bounding_box, BoundingBox::from_points([Point2::new(3, 5), Point2::new(2, 1), Point2::new(4, 3)]).unwrap());
+
+    assert_eq!((bounding_box.width(), bounding_box.height(), bounding_box.area()), (3, 5, 15));
+    assert_eq!(BoundingBox::from_corners(Point2::new(7, 7), Point2::new(7, 7)).area(), 1);
+    assert_eq!(BoundingBox::from_points([]), None);
+
+    assert!(bounding_box.contains(Point2::new(2, 1)));
+    assert!(!bounding_box.contains_interior(Point2::new(2, 1)));
+    assert!(!bounding_box.contains_interior(Point2::new(3, 5)));
+    assert!(bounding_box.contains_interior(Point2::new(3, 4)));
+    assert!(!bounding_box.contains(Point2::new(5, 3)));
+
+    assert!(bounding_box.overlaps(&BoundingBox::from_corners(Point2::new(4, 5), Point2::new(9, 9))));
+    assert!(!bounding_box.overlaps(&BoundingBox::from_corners(Point2::new(5, 0), Point2::new(9, 9))));
+
+    // nothing is strictly inside a box one cell wide
+    let thin = BoundingBox::from_corners(Point2::new(0, 0), Point2::new(0, 9));
+    assert!(!(0..10).any(|y| thin.contains_interior(Point2::new(0, y))));
+}
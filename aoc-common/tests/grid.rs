@@ -0,0 +1,77 @@
+use aoc_common::grid::Grid;
+
+// 3 wide and 2 high, so anything that mixes up width and height shows
+//   abc
+//   def
+fn grid () -> Grid<char> {
+    return Grid::parse("abc\ndef\n", Some, "a letter").unwrap();
+}
+
+fn rows (grid: &Grid<char>) -> Vec<String> {
+    return grid.rows().map(|row| row.iter().collect()).collect();
+}
+
+#[test]
+fn turning_and_flipping () {
+    let grid = grid();
+
+    assert_eq!(rows(&grid.transpose()), vec!["ad", "be", "cf"]);
+    assert_eq!(rows(&grid.rotate_clockwise()), vec!["da", "eb", "fc"]);
+    assert_eq!(rows(&grid.rotate_anticlockwise()), vec!["cf", "be", "ad"]);
+    assert_eq!(grid.transpose().transpose(), grid);
+
+    let mut clockwise = grid.clone();
+    let mut anticlockwise = grid.clone();
+    for turn in 1..=4 {
+        clockwise = clockwise.rotate_clockwise();
+        anticlockwise = anticlockwise.rotate_anticlockwise();
+        assert_eq!(clockwise == grid, turn == 4);
+        assert_eq!(anticlockwise == grid, turn == 4);
+    }
+    assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+}
+
+#[test]
+fn rows_and_columns () {
+    let grid = grid();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 1)], 'f');
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.column(0).collect::<String>(), "ad");
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    assert_eq!(grid.columns().map(|column| column.collect::<String>()).collect::<Vec<_>>(), vec!["ad", "be", "cf"]);
+}
+
+#[test]
+fn neighbours_stop_at_the_edges () {
+    let grid = grid();
+
+    assert_eq!(grid.offset(0, 0, (-1, 0)), None);
+    assert_eq!(grid.offset(2, 1, (0, 1)), None);
+    assert_eq!(grid.offset(2, 1, (-2, -1)), Some((0, 0)));
+
+    let mut corner: Vec<(usize, usize)> = grid.neighbours4(0, 0).collect();
+    corner.sort();
+    assert_eq!(corner, vec![(0, 1), (1, 0)]);
+
+    let mut corner: Vec<(usize, usize)> = grid.neighbours8(2, 1).collect();
+    corner.sort();
+    assert_eq!(corner, vec![(1, 0), (1, 1), (2, 0)]);
+
+    // on a 2 high grid every cell is on the top or bottom edge
+    assert_eq!(grid.neighbours4(1, 0).count(), 3);
+    assert_eq!(grid.neighbours8(1, 1).count(), 5);
+}
+
+#[test]
+fn ragged_rows_are_errors () {
+    let error = Grid::parse("abc\nabcd\n", Some, "a letter").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 4, "'d'"));
+
+    let error = Grid::parse("abc\nab\n", Some, "a letter").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 3, "end of line"));
+
+    let error = Grid::parse("abc\na.c\n", |c| c.is_alphabetic().then_some(c), "a letter").unwrap_err();
+    assert_eq!((error.line, error.column, error.expected.as_str()), (2, 2, "a letter"));
+}
@@ -0,0 +1,25 @@
+use aoc_common::memory;
+
+aoc_common::count_allocations!();
+
+#[test]
+fn counts_what_this_thread_allocates () {
+    memory::enable();
+
+    memory::reset();
+    let held: Vec<u64> = Vec::with_capacity(1000);
+    let freed = vec![0_u8; 4000];
+    drop(freed);
+    let usage = memory::usage();
+
+    // the freed vec was the biggest thing held at once, on top of the held one
+    assert_eq!(usage.allocations, 2);
+    assert_eq!(usage.peak_bytes, 8000 + 4000);
+
+    // other threads don't count
+    memory::reset();
+    std::thread::spawn(|| vec![0_u8; 1 << 20]).join().unwrap();
+    assert!(memory::usage().peak_bytes < 1 << 20);
+
+    drop(held);
+}
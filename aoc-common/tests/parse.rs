@@ -0,0 +1,28 @@
+use aoc_common::parse::{self, Span};
+
+#[test]
+fn errors_point_at_the_offending_text () {
+    let line = Span::line(3, "[.#] (0,7) {1,x}");
+    let sections = line.words();
+
+    let button = sections[1].delimited('(', ')', "a button").unwrap().list::<usize>(",", "a light index").unwrap();
+    assert_eq!(button, vec![0, 7]);
+
+    let error = sections[2].delimited('{', '}', "joltages").unwrap().list::<usize>(",", "a joltage").unwrap_err();
+    assert_eq!((error.line, error.column, error.found.as_str()), (3, 15, "\"x\""));
+
+    let error = Span::line(1, "4,5").tuple::<i64, 3>(",", "a coordinate").unwrap_err();
+    assert_eq!((error.column, error.found.as_str()), (4, "end of line"));
+
+    let error = Span::line(1, "11").range::<i64>("a range").unwrap_err();
+    assert_eq!((error.column, error.found.as_str()), (3, "end of line"));
+}
+
+#[test]
+fn sections_keep_their_line_numbers () {
+    let sections = parse::sections("3-5\n10-14\n\n\n1\n5\n");
+
+    let line_numbers: Vec<Vec<usize>> = sections.iter().map(|section| section.iter().map(|line| line.line_number).collect()).collect();
+    assert_eq!(line_numbers, vec![vec![1, 2], vec![5, 6]]);
+    assert_eq!(sections[0][1].range::<i64>("a range").unwrap(), (10, 14));
+}
@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use aoc_common::Answer;
+
+pub const ANSWERS_FILENAME: &str = "answers.toml";
+
+const HEADER: &str = "# answers recorded by `aoc record` and checked by `aoc verify`
+# each table is one day's answers for the input with the given FNV-1a hash
+";
+
+// known good answers for each day, keyed by a hash of the input they were found for so answers
+// for the example and the real input can live side by side
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+    entries: BTreeMap<(u32, String), BTreeMap<u32, Answer>>
+}
+
+impl AnswerStore {
+    // no file yet just means nothing has been recorded
+    pub fn load (path: &Path) -> Result<AnswerStore, String> {
+        return match fs::read_to_string(path) {
+            Ok(contents) => AnswerStore::parse(&contents).map_err(|err| format!("{}:{}", path.display(), err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(AnswerStore::default()),
+            Err(err) => Err(format!("unable to read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn save (&self, path: &Path) -> io::Result<()> {
+        return fs::write(path, self.to_toml());
+    }
+
+    pub fn get (&self, day: u32, input_hash: &str, part: u32) -> Option<&Answer> {
+        return self.entries.get(&(day, input_hash.to_string()))?.get(&part);
+    }
+
+    pub fn set (&mut self, day: u32, input_hash: &str, part: u32, answer: Answer) {
+        self.entries.entry((day, input_hash.to_string())).or_default().insert(part, answer);
+    }
+
+    // the small corner of TOML we write: `[dayN.hash]` tables of `partN = value` lines,
+    // where values are integers or strings
+    pub fn parse (contents: &str) -> Result<AnswerStore, String> {
+        let mut store = AnswerStore::default();
+        let mut table = None;
+
+        for (line_idx, line) in contents.lines().enumerate() {
+            let line_number = line_idx + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                table = Some(parse_table(header).ok_or(format!("{}: expected [dayN.hash], found {}", line_number, line))?);
+                continue;
+            }
+
+            let (day, input_hash) = match &table {
+                Some(table) => table.clone(),
+                None => return Err(format!("{}: expected a [dayN.hash] table before any answers", line_number)),
+            };
+
+            let (key, value) = line.split_once('=').ok_or(format!("{}: expected partN = answer, found {}", line_number, line))?;
+            let part = key.trim().strip_prefix("part")
+                .and_then(|p| p.parse::<u32>().ok())
+                .ok_or(format!("{}: expected part1 or part2, found {}", line_number, key.trim()))?;
+            let answer = parse_value(value.trim()).ok_or(format!("{}: expected a number or a string, found {}", line_number, value.trim()))?;
+
+            store.set(day, &input_hash, part, answer);
+        }
+
+        return Ok(store);
+    }
+
+    pub fn to_toml (&self) -> String {
+        let mut toml = HEADER.to_string();
+
+        for ((day, input_hash), answers) in self.entries.iter() {
+            toml += &format!("\n[day{}.{}]\n", day, input_hash);
+            for (part, answer) in answers {
+                toml += &format!("part{} = {}\n", part, format_value(answer));
+            }
+        }
+
+        return toml;
+    }
+}
+
+fn parse_table (header: &str) -> Option<(u32, String)> {
+    let (day, input_hash) = header.trim().split_once('.')?;
+    let day = day.strip_prefix("day")?.parse::<u32>().ok()?;
+
+    if input_hash.is_empty() || !input_hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    return Some((day, input_hash.to_string()));
+}
+
+fn parse_value (value: &str) -> Option<Answer> {
+    if let Some(text) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        let mut unescaped = String::new();
+        let mut chars = text.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next()? {
+                    '"' => unescaped.push('"'),
+                    '\\' => unescaped.push('\\'),
+                    _ => return None,
+                },
+                '"' => return None,
+                c => unescaped.push(c),
+            }
+        }
+
+        return Some(Answer::Text(unescaped));
+    }
+
+    return value.parse::<i64>().ok().map(Answer::Number);
+}
+
+fn format_value (answer: &Answer) -> String {
+    return match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(s) => format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")),
+        // never recorded, but keep the file readable if it ever is
+        Answer::Unsolved => "\"-\"".to_string(),
+    }
+}
+
+// 64 bit FNV-1a, good enough to tell inputs apart and stable across platforms and releases,
+// which std's hasher doesn't promise
+pub fn input_hash (input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    return format!("{:016x}", hash);
+}
@@ -0,0 +1,235 @@
+use std::str::FromStr;
+
+use aoc_common::{args::{self, OutputFormat}, input::{self, InputSource}, log::{self, Level}, memory};
+
+pub const USAGE: &str = "usage:
+    aoc run --day <N> [--part <1|2>] [--input <path|-> | --example] [--bench <runs> | --memory] [--format <human|json>] [-v | -vv]
+    aoc run --all [--example] [--bench <runs> | --jobs <n>] [--memory] [--format <human|json>] [-v | -vv]
+    aoc record [--day <N> [--part <1|2>] [--input <path|->] | --all] [--example]
+    aoc verify [--day <N> [--part <1|2>] [--input <path|->] | --all] [--example]
+    aoc new <N>
+    aoc fetch --day <N> [--year <year>] [--base-url <url>]
+    aoc gen --day <N> [--size <n>] [--seed <n>]
+    aoc watch --day <N> [--input <path> | --example] [--release] [-v | -vv]";
+
+// what size means is up to each day's generator, this is small enough to solve quickly everywhere
+pub const DEFAULT_GEN_SIZE: usize = 10;
+
+pub enum Command {
+    Run(RunArgs),
+    // save the current answers to the answers file
+    Record(RunArgs),
+    // check the current answers against the answers file
+    Verify(RunArgs),
+    // set up a crate for a new day
+    New(u32),
+    // download a day's input into the cache
+    Fetch(FetchArgs),
+    // print a random input for a day
+    Gen(GenArgs),
+    // rerun a day every time it changes
+    Watch(WatchArgs)
+}
+
+pub struct RunArgs {
+    // None means every day
+    pub day: Option<u32>,
+    pub part: Option<u32>,
+    pub input: InputSource,
+    // repeat each run this many times and report timing stats
+    pub bench: Option<usize>,
+    // run this many days at once, only with --all
+    pub jobs: Option<usize>,
+    // report how much each phase allocated
+    pub memory: bool,
+    pub format: OutputFormat,
+    pub log_level: Level
+}
+
+pub struct FetchArgs {
+    pub day: u32,
+    // both fall back to the environment and then the defaults in `fetch`
+    pub year: Option<u32>,
+    pub base_url: Option<String>
+}
+
+pub struct GenArgs {
+    pub day: u32,
+    pub size: usize,
+    // None picks one, which gets reported so the input can be made again
+    pub seed: Option<u64>
+}
+
+pub struct WatchArgs {
+    pub day: u32,
+    pub input: InputSource,
+    // build and run the day optimised
+    pub release: bool,
+    // passed on to the day
+    pub log_level: Level
+}
+
+pub fn parse_args (args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Err("no command given".to_string()),
+    };
+
+    if command == "new" {
+        let day = parse_number("new", args.next())?;
+        if let Some(arg) = args.next() {
+            return Err(format!("unknown argument: {}", arg));
+        }
+        return Ok(Command::New(day));
+    }
+
+    if command == "fetch" {
+        let mut day = None;
+        let mut fetch_args = FetchArgs { day: 0, year: None, base_url: None };
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => day = Some(parse_number("--day", args.next())?),
+                "--year" => fetch_args.year = Some(parse_number("--year", args.next())?),
+                "--base-url" => fetch_args.base_url = Some(expect_value("--base-url", args.next())?.clone()),
+                _ => return Err(format!("unknown argument: {}", arg)),
+            }
+        }
+
+        fetch_args.day = day.ok_or("expected --day")?;
+        return Ok(Command::Fetch(fetch_args));
+    }
+
+    if command == "gen" {
+        let mut day = None;
+        let mut gen_args = GenArgs { day: 0, size: DEFAULT_GEN_SIZE, seed: None };
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => day = Some(parse_number("--day", args.next())?),
+                "--size" => gen_args.size = parse_number("--size", args.next())?,
+                "--seed" => gen_args.seed = Some(parse_number("--seed", args.next())?),
+                _ => return Err(format!("unknown argument: {}", arg)),
+            }
+        }
+
+        gen_args.day = day.ok_or("expected --day")?;
+        return Ok(Command::Gen(gen_args));
+    }
+
+    if command == "watch" {
+        let mut day = None;
+        let mut watch_args = WatchArgs { day: 0, input: InputSource::Default, release: false, log_level: log::DEFAULT_LEVEL };
+
+        while let Some(arg) = args.next() {
+            if let Some(level) = log::verbosity_arg(arg, watch_args.log_level) {
+                watch_args.log_level = level;
+                continue;
+            }
+
+            match arg.as_str() {
+                "--day" => day = Some(parse_number("--day", args.next())?),
+                "--input" => watch_args.input = InputSource::from_arg(expect_value("--input", args.next())?),
+                "--release" => watch_args.release = true,
+                input::EXAMPLE_ARG => watch_args.input = InputSource::Example,
+                _ => return Err(format!("unknown argument: {}", arg)),
+            }
+        }
+
+        // every rerun needs to read the input again
+        if watch_args.input == InputSource::Stdin {
+            return Err("watch can't read its input from stdin".to_string());
+        }
+
+        watch_args.day = day.ok_or("expected --day")?;
+        return Ok(Command::Watch(watch_args));
+    }
+
+    let (run_args, all) = parse_run_args(args)?;
+
+    if all && run_args.day.is_some() {
+        return Err("expected only one of --day or --all".to_string());
+    }
+    if run_args.day.is_none() && (run_args.part.is_some() || !matches!(run_args.input, InputSource::Default | InputSource::Example)) {
+        return Err("--part and --input can only be used with --day".to_string());
+    }
+    if run_args.part.is_some_and(|part| part != 1 && part != 2) {
+        return Err("--part must be 1 or 2".to_string());
+    }
+    if run_args.jobs.is_some() && (!all || command != "run") {
+        return Err("--jobs can only be used with run --all".to_string());
+    }
+    // days running side by side would skew each other's timings
+    if run_args.jobs.is_some() && run_args.bench.is_some() {
+        return Err("expected only one of --bench or --jobs".to_string());
+    }
+    // counting slows down every allocation, which would skew the timings
+    if run_args.memory && run_args.bench.is_some() {
+        return Err(format!("expected only one of --bench or {}", memory::MEMORY_ARG));
+    }
+
+    match command.as_str() {
+        "run" => {
+            if !all && run_args.day.is_none() {
+                return Err("expected one of --day or --all".to_string());
+            }
+            return Ok(Command::Run(run_args));
+        },
+        // record and verify cover every day unless told otherwise
+        "record" | "verify" => {
+            if run_args.bench.is_some() || run_args.memory || run_args.format != OutputFormat::Human {
+                return Err(format!("--bench, --memory and --format can't be used with {}", command));
+            }
+            if command == "record" {
+                return Ok(Command::Record(run_args));
+            }
+            return Ok(Command::Verify(run_args));
+        },
+        _ => return Err(format!("unknown command: {}", command)),
+    }
+}
+
+// the flags shared by every command, along with whether --all was given
+fn parse_run_args<'a> (mut args: impl Iterator<Item = &'a String>) -> Result<(RunArgs, bool), String> {
+    let mut run_args = RunArgs { day: None, part: None, input: InputSource::Default, bench: None, jobs: None, memory: false, format: OutputFormat::Human, log_level: log::DEFAULT_LEVEL };
+    let mut all = false;
+
+    while let Some(arg) = args.next() {
+        if let Some(level) = log::verbosity_arg(arg, run_args.log_level) {
+            run_args.log_level = level;
+            continue;
+        }
+
+        match arg.as_str() {
+            "--all" => all = true,
+            "--day" => run_args.day = Some(parse_number("--day", args.next())?),
+            "--part" => run_args.part = Some(parse_number("--part", args.next())?),
+            "--input" => run_args.input = InputSource::from_arg(expect_value("--input", args.next())?),
+            args::BENCH_ARG => run_args.bench = Some(args::parse_bench(args.next())?),
+            "--jobs" => {
+                let jobs = parse_number("--jobs", args.next())?;
+                if jobs == 0 {
+                    return Err("--jobs must be at least 1".to_string());
+                }
+                run_args.jobs = Some(jobs);
+            },
+            memory::MEMORY_ARG => run_args.memory = true,
+            args::FORMAT_ARG => run_args.format = args::parse_format(args.next())?,
+            input::EXAMPLE_ARG => run_args.input = InputSource::Example,
+            _ => return Err(format!("unknown argument: {}", arg)),
+        }
+    }
+
+    return Ok((run_args, all));
+}
+
+fn expect_value<'a> (flag: &str, value: Option<&'a String>) -> Result<&'a String, String> {
+    return value.ok_or(format!("{} needs a value", flag));
+}
+
+fn parse_number<T: FromStr> (flag: &str, value: Option<&String>) -> Result<T, String> {
+    let value = expect_value(flag, value)?;
+    return value.parse::<T>().map_err(|_| format!("{} expects a number, got {}", flag, value));
+}
@@ -0,0 +1,39 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::{random::Generator, solution::{run, Runner}};
+
+pub struct Day {
+    pub number: u32,
+    pub run: Runner,
+    // makes random inputs for `aoc gen`
+    pub generate: Generator
+}
+
+impl Day {
+    pub fn dir (&self) -> PathBuf {
+        return workspace_root().join(format!("day{}", self.number));
+    }
+}
+
+pub const DAYS: [Day; 12] = [
+    Day { number: 1, run: run::<day1::Day1>, generate: day1::generator::generate },
+    Day { number: 2, run: run::<day2::Day2>, generate: day2::generator::generate },
+    Day { number: 3, run: run::<day3::Day3>, generate: day3::generator::generate },
+    Day { number: 4, run: run::<day4::Day4>, generate: day4::generator::generate },
+    Day { number: 5, run: run::<day5::Day5>, generate: day5::generator::generate },
+    Day { number: 6, run: run::<day6::Day6>, generate: day6::generator::generate },
+    Day { number: 7, run: run::<day7::Day7>, generate: day7::generator::generate },
+    Day { number: 8, run: run::<day8::Day8>, generate: day8::generator::generate },
+    Day { number: 9, run: run::<day9::Day9>, generate: day9::generator::generate },
+    Day { number: 10, run: run::<day10::Day10>, generate: day10::generator::generate },
+    Day { number: 11, run: run::<day11::Day11>, generate: day11::generator::generate },
+    Day { number: 12, run: run::<day12::Day12>, generate: day12::generator::generate },
+];
+
+pub fn find (number: u32) -> Option<&'static Day> {
+    return DAYS.iter().find(|day| day.number == number);
+}
+
+pub fn workspace_root () -> &'static Path {
+    return Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+}
@@ -0,0 +1,95 @@
+// downloads puzzle inputs into the cache, where the runner and the day binaries pick them up
+// whenever a day has no input.txt of its own
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::input;
+
+use crate::{cli::FetchArgs, http};
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u32 = 2025;
+
+const USER_AGENT: &str = "aoc-runner (local puzzle input cache)";
+
+pub enum Fetched {
+    Downloaded(PathBuf),
+    // already cached, nothing was requested
+    Cached(PathBuf)
+}
+
+pub fn fetch (workspace_root: &Path, fetch_args: &FetchArgs) -> Result<Fetched, String> {
+    let cached = input::cached_input(workspace_root, fetch_args.day);
+
+    // inputs never change once a puzzle is out, so there's never a reason to ask twice
+    if cached.exists() {
+        return Ok(Fetched::Cached(cached));
+    }
+
+    let year = match fetch_args.year {
+        Some(year) => year,
+        None => match std::env::var(YEAR_ENV) {
+            Ok(year) => year.trim().parse::<u32>().map_err(|_| format!("{} should be a year, got {:?}", YEAR_ENV, year))?,
+            Err(_) => DEFAULT_YEAR,
+        },
+    };
+    let base_url = match &fetch_args.base_url {
+        Some(base_url) => base_url.clone(),
+        None => std::env::var(BASE_URL_ENV).unwrap_or(DEFAULT_BASE_URL.to_string()),
+    };
+    let url = format!("{}/{}/day/{}/input", base_url.trim_end_matches('/'), year, fetch_args.day);
+
+    let cookie = format!("session={}", session_token()?);
+    let response = http::get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])?;
+
+    if response.status != 200 {
+        return Err(format!("{} returned {}: {}", url, response.status, response.body.trim()));
+    }
+
+    // write to the side and move into place so an interrupted download never looks cached
+    let cache_dir = cached.parent().unwrap();
+    fs::create_dir_all(cache_dir).map_err(|err| format!("unable to create {}: {}", cache_dir.display(), err))?;
+
+    let partial = cached.with_extension("part");
+    fs::write(&partial, &response.body).map_err(|err| format!("unable to write {}: {}", partial.display(), err))?;
+    fs::rename(&partial, &cached).map_err(|err| format!("unable to move {} into place: {}", partial.display(), err))?;
+
+    return Ok(Fetched::Downloaded(cached));
+}
+
+// the session cookie from the site, either straight from the environment or from a file
+// defaulting to ~/.config/aoc/session
+fn session_token () -> Result<String, String> {
+    if let Ok(token) = std::env::var(SESSION_ENV) {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+    }
+
+    let path = match session_file() {
+        Some(path) => path,
+        None => return Err(format!("no session token, set {} or {}", SESSION_ENV, SESSION_FILE_ENV)),
+    };
+
+    return match fs::read_to_string(&path) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        Ok(_) => Err(format!("{} is empty", path.display())),
+        Err(_) => Err(format!("no session token, set {} or put it in {}", SESSION_ENV, path.display())),
+    }
+}
+
+fn session_file () -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(SESSION_FILE_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    if let Some(config_home) = std::env::var_os("XDG_CONFIG_HOME") {
+        return Some(PathBuf::from(config_home).join("aoc").join("session"));
+    }
+    return std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config").join("aoc").join("session"));
+}
@@ -0,0 +1,140 @@
+// just enough of an HTTP client to download puzzle inputs
+// plain http is spoken directly, which is what a local stand-in for the real site uses,
+// https goes through curl so we don't need a TLS implementation of our own
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct Response {
+    pub status: u16,
+    pub body: String
+}
+
+pub fn get (url: &str, headers: &[(&str, &str)]) -> Result<Response, String> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        return get_plain(rest, headers);
+    }
+    if url.starts_with("https://") {
+        return get_with_curl(url, headers);
+    }
+    return Err(format!("unsupported url {}, expected http:// or https://", url));
+}
+
+fn get_plain (url: &str, headers: &[(&str, &str)]) -> Result<Response, String> {
+    let (authority, path) = match url.find('/') {
+        Some(idx) => (&url[..idx], &url[idx..]),
+        None => (url, "/"),
+    };
+    let address = if authority.contains(':') { authority.to_string() } else { format!("{}:80", authority) };
+
+    let mut stream = TcpStream::connect(&address).map_err(|err| format!("unable to connect to {}: {}", address, err))?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(|err| err.to_string())?;
+    stream.set_write_timeout(Some(TIMEOUT)).map_err(|err| err.to_string())?;
+
+    let mut request = format!("GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n", path, authority);
+    for (name, value) in headers {
+        request += &format!("{}: {}\r\n", name, value);
+    }
+    request += "\r\n";
+
+    stream.write_all(request.as_bytes()).map_err(|err| format!("unable to send request to {}: {}", address, err))?;
+
+    let mut raw = vec![];
+    stream.read_to_end(&mut raw).map_err(|err| format!("unable to read response from {}: {}", address, err))?;
+
+    return parse_response(&raw);
+}
+
+fn parse_response (raw: &[u8]) -> Result<Response, String> {
+    let header_end = raw.windows(4).position(|w| w == b"\r\n\r\n").ok_or("response ended before its headers did")?;
+    let head = String::from_utf8_lossy(&raw[..header_end]);
+    let body = &raw[header_end + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status_line = lines.next().unwrap_or("");
+    let status = status_line.split_whitespace().nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or(format!("malformed status line {:?}", status_line))?;
+
+    let mut chunked = false;
+    let mut content_length = None;
+
+    for line in lines {
+        let (name, value) = match line.split_once(':') {
+            Some(header) => header,
+            None => continue,
+        };
+        let value = value.trim();
+
+        if name.eq_ignore_ascii_case("transfer-encoding") && value.eq_ignore_ascii_case("chunked") {
+            chunked = true;
+        } else if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse::<usize>().ok();
+        }
+    }
+
+    let body = if chunked {
+        dechunk(body)?
+    } else {
+        match content_length {
+            Some(length) if length <= body.len() => body[..length].to_vec(),
+            Some(length) => return Err(format!("response ended after {} of {} bytes", body.len(), length)),
+            None => body.to_vec(),
+        }
+    };
+
+    let body = String::from_utf8(body).map_err(|_| "response body isn't valid utf-8".to_string())?;
+    return Ok(Response { status: status, body: body });
+}
+
+fn dechunk (mut body: &[u8]) -> Result<Vec<u8>, String> {
+    let mut dechunked = vec![];
+
+    loop {
+        let line_end = body.windows(2).position(|w| w == b"\r\n").ok_or("chunk size missing its line ending")?;
+        let size_line = String::from_utf8_lossy(&body[..line_end]);
+        // chunk extensions come after a semicolon and can be ignored
+        let size_hex = size_line.split(';').next().unwrap().trim();
+        let size = usize::from_str_radix(size_hex, 16).map_err(|_| format!("malformed chunk size {:?}", size_hex))?;
+
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Ok(dechunked);
+        }
+        if body.len() < size + 2 {
+            return Err("response ended part way through a chunk".to_string());
+        }
+
+        dechunked.extend_from_slice(&body[..size]);
+        body = &body[size + 2..];
+    }
+}
+
+fn get_with_curl (url: &str, headers: &[(&str, &str)]) -> Result<Response, String> {
+    // headers go in on stdin so the session token doesn't show up in the process list
+    let mut curl = Command::new("curl")
+        .args(["--silent", "--show-error", "--max-time", &TIMEOUT.as_secs().to_string(), "--header", "@-", "--write-out", "\n%{http_code}", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("unable to run curl for {}: {}", url, err))?;
+
+    let header_lines: String = headers.iter().map(|(name, value)| format!("{}: {}\n", name, value)).collect();
+    curl.stdin.take().unwrap().write_all(header_lines.as_bytes()).map_err(|err| format!("unable to pass headers to curl: {}", err))?;
+
+    let output = curl.wait_with_output().map_err(|err| format!("curl failed: {}", err))?;
+    if !output.status.success() {
+        return Err(format!("curl failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+
+    let stdout = String::from_utf8(output.stdout).map_err(|_| "response body isn't valid utf-8".to_string())?;
+    let (body, status) = stdout.rsplit_once('\n').ok_or("curl didn't report a status code")?;
+    let status = status.trim().parse::<u16>().map_err(|_| format!("curl reported a malformed status code {:?}", status))?;
+
+    return Ok(Response { status: status, body: body.to_string() });
+}
@@ -0,0 +1,454 @@
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_common::{args::OutputFormat, error, info, json::Json, log, memory::{self, format_bytes}, random::Rng, report, solution::{self, Run, PARTS}, timing::{self, format_duration, Timings}, Answer};
+
+mod answers;
+mod cli;
+mod days;
+mod fetch;
+mod http;
+mod pool;
+mod scaffold;
+mod watch;
+
+use answers::AnswerStore;
+use cli::{Command, RunArgs};
+use days::Day;
+
+aoc_common::count_allocations!();
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let command = match cli::parse_args(&args) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{}", err);
+            eprintln!("{}", cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    return match command {
+        Command::Run(run_args) => {
+            log::set_level(run_args.log_level);
+            if run_args.memory {
+                memory::enable();
+            }
+            match run_args.day {
+                Some(day) => run_day(day, &run_args),
+                None => run_all(&run_args),
+            }
+        },
+        Command::Record(run_args) => {
+            log::set_level(run_args.log_level);
+            record(&run_args)
+        },
+        Command::Verify(run_args) => {
+            log::set_level(run_args.log_level);
+            verify(&run_args)
+        },
+        Command::New(day) => new_day(day),
+        Command::Fetch(fetch_args) => fetch_input(&fetch_args),
+        Command::Gen(gen_args) => generate(&gen_args),
+        Command::Watch(watch_args) => {
+            log::set_level(watch_args.log_level);
+            watch::watch(days::workspace_root(), &watch_args)
+        },
+    };
+}
+
+fn run_day (number: u32, run_args: &RunArgs) -> ExitCode {
+    let day = match days::find(number) {
+        Some(day) => day,
+        None => {
+            error!("no solution for day {}", number);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = match run_args.part {
+        Some(part) => vec![part],
+        None => PARTS.to_vec(),
+    };
+    let input_name = run_args.input.name(&day.dir());
+    let input = match run_args.input.read(&day.dir()) {
+        Ok(input) => input,
+        Err(err) => return report::read_error(&input_name, &err),
+    };
+
+    if let Some(runs) = run_args.bench {
+        let result = solution::bench(day.run, &input, &parts, runs);
+        match (&result, run_args.format) {
+            (_, OutputFormat::Json) => println!("{}", report::bench_json(day.number, &input_name, &result)),
+            (Ok(stats), OutputFormat::Human) => timing::print_bench(&format!("Day {}", day.number), stats),
+            (Err(_), OutputFormat::Human) => {},
+        }
+        return report::exit_code(&result, &input_name);
+    }
+
+    let result = (day.run)(&input, &parts);
+    match (&result, run_args.format) {
+        (_, OutputFormat::Json) => println!("{}", report::run_json(day.number, &input_name, &result)),
+        (Ok(result), OutputFormat::Human) => {
+            for (part, answer) in result.answers.iter() {
+                println!("Day {} part {}: {}", day.number, part, answer);
+            }
+            report::stats(&result.stats);
+            report::timings(&result.timings);
+            if let Some(memory) = &result.memory {
+                report::memory(memory);
+            }
+        },
+        (Err(_), OutputFormat::Human) => {},
+    }
+
+    return report::exit_code(&result, &input_name);
+}
+
+fn run_all (run_args: &RunArgs) -> ExitCode {
+    if let Some(runs) = run_args.bench {
+        return bench_all(run_args, runs);
+    }
+
+    let started = Instant::now();
+    // without --jobs the days still go through the pool, one at a time and in order
+    let outcomes = pool::map(&days::DAYS, run_args.jobs.unwrap_or(1), |day| {
+        return run_args.input.read(&day.dir()).map(|input| (day.run)(&input, &PARTS));
+    });
+    let wall_time = started.elapsed();
+
+    let mut rows = vec![];
+    let mut days_json = vec![];
+    let mut failed = false;
+
+    for (day, outcome) in days::DAYS.iter().zip(outcomes) {
+        let input_name = run_args.input.name(&day.dir());
+
+        let result = match outcome {
+            Ok(Ok(result)) => result,
+            // the rest of the days carry on regardless
+            Ok(Err(err)) => {
+                report::read_error(&input_name, &err);
+                failed = true;
+                match run_args.format {
+                    OutputFormat::Json => days_json.push(report::read_error_json(day.number, &input_name, &err)),
+                    OutputFormat::Human => rows.push((day.number, failed_run("unreadable"))),
+                }
+                continue;
+            },
+            Err(message) => {
+                error!("day {} panicked: {}", day.number, message);
+                failed = true;
+                match run_args.format {
+                    OutputFormat::Json => days_json.push(report::panic_json(day.number, &input_name, &message)),
+                    OutputFormat::Human => rows.push((day.number, failed_run("panicked"))),
+                }
+                continue;
+            }
+        };
+        failed |= report::exit_code(&result, &input_name) != ExitCode::SUCCESS;
+
+        if run_args.format == OutputFormat::Json {
+            days_json.push(report::run_json(day.number, &input_name, &result));
+            continue;
+        }
+        rows.push((day.number, result.unwrap_or(failed_run("parse error"))));
+    }
+
+    match (run_args.format, run_args.jobs) {
+        (OutputFormat::Json, _) => println!("{}", Json::object().with("days", days_json)),
+        (OutputFormat::Human, None) => {
+            print_table(&rows);
+            print_memory_table(&rows);
+        },
+        (OutputFormat::Human, Some(jobs)) => {
+            // slowest first, since that's where the time goes
+            rows.sort_by_key(|(_, result)| std::cmp::Reverse(result.timings.total()));
+            print_table(&rows);
+            print_memory_table(&rows);
+
+            let solving: Duration = rows.iter().map(|(_, result)| result.timings.total()).sum();
+            info!("Ran {} days on {} threads in {}, {} spent solving", rows.len(), jobs, format_duration(wall_time), format_duration(solving));
+        },
+    }
+
+    if failed {
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+// days are benchmarked one at a time, so they don't skew each other's timings
+fn bench_all (run_args: &RunArgs, runs: usize) -> ExitCode {
+    let mut days_json = vec![];
+    let mut failed = false;
+
+    for day in days::DAYS.iter() {
+        let input_name = run_args.input.name(&day.dir());
+        let input = match run_args.input.read(&day.dir()) {
+            Ok(input) => input,
+            Err(err) => {
+                failed = true;
+                report::read_error(&input_name, &err);
+                if run_args.format == OutputFormat::Json {
+                    days_json.push(report::read_error_json(day.number, &input_name, &err));
+                }
+                continue;
+            }
+        };
+
+        let result = solution::bench(day.run, &input, &PARTS, runs);
+        match (&result, run_args.format) {
+            (_, OutputFormat::Json) => days_json.push(report::bench_json(day.number, &input_name, &result)),
+            (Ok(stats), OutputFormat::Human) => timing::print_bench(&format!("Day {}", day.number), stats),
+            (Err(_), OutputFormat::Human) => {},
+        }
+        // keep going so one bad input doesn't hide every other day
+        failed |= report::exit_code(&result, &input_name) != ExitCode::SUCCESS;
+    }
+
+    if run_args.format == OutputFormat::Json {
+        println!("{}", Json::object().with("days", days_json));
+    }
+
+    if failed {
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+// a row for the table when a day didn't get as far as answers
+fn failed_run (reason: &str) -> Run {
+    return Run {
+        answers: vec![(1, Answer::from(reason)), (2, Answer::from(reason))],
+        timings: Timings::default(),
+        memory: None,
+        stats: vec![]
+    };
+}
+
+fn print_table (rows: &[(u32, Run)]) {
+    println!("{:>3} | {:>20} | {:>20} | {:>12} | {:>12} | {:>12} | {:>12}", "Day", "Part 1", "Part 2", "Parse", "Part 1", "Part 2", "Total");
+    println!("{}", "-".repeat(118));
+
+    for (number, result) in rows {
+        let timings = &result.timings;
+        let part_time = |part: u32| timings.part(part).map(format_duration).unwrap_or("-".to_string());
+
+        println!(
+            "{:>3} | {:>20} | {:>20} | {:>12} | {:>12} | {:>12} | {:>12}",
+            number, result.answer(1).to_string(), result.answer(2).to_string(),
+            format_duration(timings.parse), part_time(1), part_time(2), format_duration(timings.total())
+        );
+    }
+}
+
+// only days that got as far as running have anything to show, and only when counting was asked for
+fn print_memory_table (rows: &[(u32, Run)]) {
+    if !rows.iter().any(|(_, result)| result.memory.is_some()) {
+        return;
+    }
+
+    println!();
+    println!("{:>3} | {:>12} | {:>12} | {:>12} | {:>12}", "Day", "Parse peak", "Part 1 peak", "Part 2 peak", "Allocations");
+    println!("{}", "-".repeat(66));
+
+    for (number, result) in rows {
+        let memory = match &result.memory {
+            Some(memory) => memory,
+            None => continue,
+        };
+        let part_peak = |part: u32| memory.part(part).map(|usage| format_bytes(usage.peak_bytes)).unwrap_or("-".to_string());
+        let allocations = memory.parse.allocations + [1, 2].iter().filter_map(|part| memory.part(*part)).map(|usage| usage.allocations).sum::<usize>();
+
+        println!("{:>3} | {:>12} | {:>12} | {:>12} | {:>12}", number, format_bytes(memory.parse.peak_bytes), part_peak(1), part_peak(2), allocations);
+    }
+}
+
+// the days a command should cover, every day unless one was picked
+fn selected_days (run_args: &RunArgs) -> Option<Vec<&'static Day>> {
+    return match run_args.day {
+        Some(number) => match days::find(number) {
+            Some(day) => Some(vec![day]),
+            None => {
+                error!("no solution for day {}", number);
+                None
+            }
+        },
+        None => Some(days::DAYS.iter().collect()),
+    }
+}
+
+// runs each selected day and hands its answers over along with the hash of the input they're for,
+// returning false if any day couldn't be run
+fn for_each_answer (run_args: &RunArgs, days: &[&Day], mut handle: impl FnMut(&Day, &str, u32, Answer)) -> bool {
+    let parts = match run_args.part {
+        Some(part) => vec![part],
+        None => PARTS.to_vec(),
+    };
+    let mut ok = true;
+
+    for day in days {
+        let input_name = run_args.input.name(&day.dir());
+        let input = match run_args.input.read(&day.dir()) {
+            Ok(input) => input,
+            Err(err) => {
+                report::read_error(&input_name, &err);
+                ok = false;
+                continue;
+            }
+        };
+        let result = (day.run)(&input, &parts);
+
+        if report::exit_code(&result, &input_name) != ExitCode::SUCCESS {
+            ok = false;
+            continue;
+        }
+
+        let input_hash = answers::input_hash(&input);
+        for (part, answer) in result.unwrap().answers {
+            handle(day, &input_hash, part, answer);
+        }
+    }
+
+    return ok;
+}
+
+fn record (run_args: &RunArgs) -> ExitCode {
+    let days = match selected_days(run_args) {
+        Some(days) => days,
+        None => return ExitCode::FAILURE,
+    };
+
+    let path = days::workspace_root().join(answers::ANSWERS_FILENAME);
+    let mut store = match AnswerStore::load(&path) {
+        Ok(store) => store,
+        Err(err) => {
+            error!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let ok = for_each_answer(run_args, &days, |day, input_hash, part, answer| {
+        // nothing to check against later
+        if answer == Answer::Unsolved {
+            return;
+        }
+
+        match store.get(day.number, input_hash, part) {
+            Some(previous) if *previous != answer => println!("Day {} part {}: {} (was {})", day.number, part, answer, previous),
+            _ => println!("Day {} part {}: {}", day.number, part, answer),
+        }
+        store.set(day.number, input_hash, part, answer);
+    });
+
+    if let Err(err) = store.save(&path) {
+        error!("unable to write {}: {}", path.display(), err);
+        return ExitCode::FAILURE;
+    }
+    info!("Recorded answers in {}", path.display());
+
+    if !ok {
+        return ExitCode::FAILURE;
+    }
+    return ExitCode::SUCCESS;
+}
+
+fn verify (run_args: &RunArgs) -> ExitCode {
+    let days = match selected_days(run_args) {
+        Some(days) => days,
+        None => return ExitCode::FAILURE,
+    };
+
+    let path = days::workspace_root().join(answers::ANSWERS_FILENAME);
+    let store = match AnswerStore::load(&path) {
+        Ok(store) => store,
+        Err(err) => {
+            error!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut n_matched = 0;
+    let mut n_changed = 0;
+    let mut n_missing = 0;
+
+    let ok = for_each_answer(run_args, &days, |day, input_hash, part, answer| {
+        match store.get(day.number, input_hash, part) {
+            Some(expected) if *expected == answer => {
+                println!("Day {} part {}: {} ok", day.number, part, answer);
+                n_matched += 1;
+            },
+            Some(expected) => {
+                println!("Day {} part {}: {} CHANGED, expected {}", day.number, part, answer, expected);
+                n_changed += 1;
+            },
+            // unsolved parts have nothing to check
+            None if answer == Answer::Unsolved => {},
+            None => {
+                println!("Day {} part {}: {} NOT RECORDED for input {}", day.number, part, answer, input_hash);
+                n_missing += 1;
+            },
+        }
+    });
+
+    if !ok || n_changed > 0 || n_missing > 0 {
+        error!("verify failed: {} changed, {} not recorded, {} ok", n_changed, n_missing, n_matched);
+        return ExitCode::FAILURE;
+    }
+
+    info!("All {} answers match {}", n_matched, path.display());
+    return ExitCode::SUCCESS;
+}
+
+fn new_day (day: u32) -> ExitCode {
+    return match scaffold::new_day(days::workspace_root(), day) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            info!("Day {} is ready, put the example in day{}/example.txt and its answers in day{}/example.answers", day, day, day);
+            ExitCode::SUCCESS
+        },
+        Err(err) => {
+            error!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch_input (fetch_args: &cli::FetchArgs) -> ExitCode {
+    return match fetch::fetch(days::workspace_root(), fetch_args) {
+        Ok(fetch::Fetched::Downloaded(path)) => {
+            println!("Day {} input saved to {}", fetch_args.day, path.display());
+            ExitCode::SUCCESS
+        },
+        Ok(fetch::Fetched::Cached(path)) => {
+            println!("Day {} input already cached at {}", fetch_args.day, path.display());
+            ExitCode::SUCCESS
+        },
+        Err(err) => {
+            error!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// the input goes to stdout so it can be piped straight into `aoc run --day N --input -`
+fn generate (gen_args: &cli::GenArgs) -> ExitCode {
+    let day = match days::find(gen_args.day) {
+        Some(day) => day,
+        None => {
+            error!("no solution for day {}", gen_args.day);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let seed = gen_args.seed.unwrap_or_else(Rng::seed_from_time);
+    print!("{}", (day.generate)(&mut Rng::new(seed), gen_args.size));
+    info!("Generated day {} input of size {} with seed {}", day.number, gen_args.size, seed);
+
+    return ExitCode::SUCCESS;
+}
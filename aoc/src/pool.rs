@@ -0,0 +1,44 @@
+// runs a job for each item on a fixed number of threads, for `aoc run --all --jobs N`
+// each job's panic is caught and handed back as its result, so one bad day can't take the rest down
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+// results come back in the same order as the items, whichever order they finished in
+pub fn map<T: Sync, R: Send> (items: &[T], n_threads: usize, job: impl Fn(&T) -> R + Sync) -> Vec<Result<R, String>> {
+    let next_item = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<R, String>>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..n_threads.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                // each thread takes the next item nobody has started yet until they're all gone
+                loop {
+                    let idx = next_item.fetch_add(1, Ordering::SeqCst);
+                    if idx >= items.len() {
+                        break;
+                    }
+
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| job(&items[idx]))).map_err(panic_message);
+                    results.lock().unwrap()[idx] = Some(result);
+                }
+            });
+        }
+    });
+
+    return results.into_inner().unwrap().into_iter().map(|result| result.unwrap()).collect();
+}
+
+// panics carry whatever was passed to panic!, which is nearly always a string of some sort
+fn panic_message (payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+    return "panicked with something other than a message".to_string();
+}
@@ -0,0 +1,191 @@
+// `aoc new` sets up a crate for a new day and registers it everywhere the runner needs to know
+// about it, so the only thing left to write is the solution itself
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{day}"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-common.workspace = true
+
+[lints]
+workspace = true
+"#;
+
+const LIB_RS: &str = r#"use aoc_common::{parse, solution, Answer, ParseError, Solution};
+
+pub mod generator;
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day{day}>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day{day}>(input);
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u32 = {day};
+    type Input = Vec<String>;
+
+    fn parse (input: &str) -> Result<Vec<String>, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1 (_lines: &Vec<String>) -> Answer {
+        return Answer::Unsolved;
+    }
+
+    fn part2 (_lines: &Vec<String>) -> Answer {
+        return Answer::Unsolved;
+    }
+}
+
+fn parse_input (input: &str) -> Result<Vec<String>, ParseError> {
+    return parse::each_line(input, |line| Ok(line.text.to_string()));
+}
+"#;
+
+const GENERATOR_RS: &str = r#"// `size` lines of random input, to be replaced once the input format is known
+
+use aoc_common::random::Rng;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..size.max(1) {
+        lines.push(rng.range(0, 100).to_string());
+    }
+
+    return lines.join("\n") + "\n";
+}
+"#;
+
+const MAIN_RS: &str = r#"use std::process::ExitCode;
+
+aoc_common::count_allocations!();
+
+fn main() -> ExitCode {
+    return aoc_common::solution::main::<day{day}::Day{day}>(env!("CARGO_MANIFEST_DIR"));
+}
+"#;
+
+const EXAMPLES_RS: &str = r#"#[test]
+fn examples () {
+    aoc_common::testing::check_examples::<day{day}::Day{day}>(env!("CARGO_MANIFEST_DIR"));
+}
+"#;
+
+const GENERATED_RS: &str = r#"use aoc_common::testing;
+
+#[test]
+fn generated () {
+    testing::check_generated::<day{day}::Day{day}>(day{day}::generator::generate, &[1, 10, 100], testing::any_answers);
+}
+"#;
+
+const EXAMPLE_ANSWERS: &str = "# fill in the answers from the puzzle text once the example is in example.txt
+# part1: <answer>
+# part2: <answer>
+";
+
+const MAX_DAY: u32 = 25;
+
+// creates the day's crate and registers it, returning every file it wrote or changed
+pub fn new_day (root: &Path, day: u32) -> Result<Vec<PathBuf>, String> {
+    if day == 0 || day > MAX_DAY {
+        return Err(format!("day must be between 1 and {}", MAX_DAY));
+    }
+
+    let day_dir = root.join(format!("day{}", day));
+    if day_dir.exists() {
+        return Err(format!("{} already exists, not overwriting it", day_dir.display()));
+    }
+
+    // work out every edit before touching anything, so a failure doesn't leave a half made day
+    let workspace_toml = root.join("Cargo.toml");
+    let runner_toml = root.join("aoc").join("Cargo.toml");
+    let days_rs = root.join("aoc").join("src").join("days.rs");
+
+    let workspace_edit = register(&workspace_toml, day, &format!("    \"day{}\",", day), |line| {
+        return line.trim().strip_prefix("\"day")?.strip_suffix("\",")?.parse().ok();
+    })?;
+    let runner_edit = register(&runner_toml, day, &format!("day{} = {{ path = \"../day{}\" }}", day, day), |line| {
+        return line.strip_prefix("day")?.split_once(" = ")?.0.parse().ok();
+    })?;
+    let days_edit = register(&days_rs, day, &format!("    Day {{ number: {}, run: run::<day{}::Day{}>, generate: day{}::generator::generate }},", day, day, day, day), |line| {
+        return line.trim().strip_prefix("Day { number: ")?.split_once(',')?.0.parse().ok();
+    })?;
+    let days_edit = resize_days(&days_edit)?;
+
+    let files = [
+        (day_dir.join("Cargo.toml"), CARGO_TOML),
+        (day_dir.join("src").join("lib.rs"), LIB_RS),
+        (day_dir.join("src").join("generator.rs"), GENERATOR_RS),
+        (day_dir.join("src").join("main.rs"), MAIN_RS),
+        (day_dir.join("tests").join("examples.rs"), EXAMPLES_RS),
+        (day_dir.join("tests").join("generated.rs"), GENERATED_RS),
+        (day_dir.join("example.txt"), ""),
+        (day_dir.join("example.answers"), EXAMPLE_ANSWERS),
+    ];
+
+    let mut written = vec![];
+
+    for (path, template) in files {
+        fs::create_dir_all(path.parent().unwrap()).map_err(|err| format!("unable to create {}: {}", path.display(), err))?;
+        write(&path, &template.replace("{day}", &day.to_string()))?;
+        written.push(path);
+    }
+
+    for (path, contents) in [(workspace_toml, workspace_edit), (runner_toml, runner_edit), (days_rs, days_edit)] {
+        write(&path, &contents)?;
+        written.push(path);
+    }
+
+    return Ok(written);
+}
+
+// adds `new_line` to the list of days in `path`, keeping them in day order
+// `day_of` picks out which day an existing line is for, if it's one of the list
+fn register (path: &Path, day: u32, new_line: &str, day_of: impl Fn(&str) -> Option<u32>) -> Result<String, String> {
+    let contents = fs::read_to_string(path).map_err(|err| format!("unable to read {}: {}", path.display(), err))?;
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    let registered: Vec<(usize, u32)> = lines.iter().enumerate().filter_map(|(idx, line)| Some((idx, day_of(line)?))).collect();
+
+    if registered.iter().any(|(_, registered_day)| *registered_day == day) {
+        return Err(format!("day {} is already registered in {}", day, path.display()));
+    }
+
+    let insert_at = match registered.iter().find(|(_, registered_day)| *registered_day > day) {
+        Some((idx, _)) => *idx,
+        None => match registered.last() {
+            Some((idx, _)) => idx + 1,
+            None => return Err(format!("couldn't find the list of days in {}", path.display())),
+        },
+    };
+    lines.insert(insert_at, new_line);
+
+    return Ok(lines.join("\n") + "\n");
+}
+
+// the runner's table of days is a fixed size array, so it needs to grow by one
+fn resize_days (days_rs: &str) -> Result<String, String> {
+    let n_days = days_rs.lines().filter(|line| line.trim().starts_with("Day { number: ")).count();
+    let old_declaration = format!("pub const DAYS: [Day; {}]", n_days - 1);
+
+    if !days_rs.contains(&old_declaration) {
+        return Err(format!("expected to find `{}` in the runner's days", old_declaration));
+    }
+    return Ok(days_rs.replace(&old_declaration, &format!("pub const DAYS: [Day; {}]", n_days)));
+}
+
+fn write (path: &Path, contents: &str) -> Result<(), String> {
+    return fs::write(path, contents).map_err(|err| format!("unable to write {}: {}", path.display(), err));
+}
@@ -0,0 +1,206 @@
+// `aoc watch` reruns a day whenever its code, input or examples change
+// it polls modification times rather than asking the OS to report changes, so it works the same
+// everywhere, and it goes through `cargo run` so edits to the solution get rebuilt first
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use aoc_common::{error, info, input::{InputSource, EXAMPLE_ARG}, log::{Level, VERBOSE_ARG, VERY_VERBOSE_ARG}, testing};
+
+use crate::cli::WatchArgs;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+// how long things have to stay unchanged before rerunning, so saving a few files at once or a
+// build writing its output only causes the one run
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+// when each watched file was last modified
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+pub fn watch (root: &Path, watch_args: &WatchArgs) -> ExitCode {
+    let day_dir = root.join(format!("day{}", watch_args.day));
+    if !day_dir.is_dir() {
+        error!("no solution for day {}", watch_args.day);
+        return ExitCode::FAILURE;
+    }
+
+    info!("Watching day {}, press ctrl-c to stop", watch_args.day);
+
+    let mut answers = run(root, watch_args, &[]);
+    // taken after the run, so the build it just did doesn't count as a change
+    let mut last_run = snapshot(&watched_paths(root, &day_dir, watch_args));
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let mut current = snapshot(&watched_paths(root, &day_dir, watch_args));
+        if current == last_run {
+            continue;
+        }
+
+        // wait for things to settle down before running
+        let mut quiet_since = Instant::now();
+        while quiet_since.elapsed() < DEBOUNCE {
+            thread::sleep(POLL_INTERVAL);
+
+            let latest = snapshot(&watched_paths(root, &day_dir, watch_args));
+            if latest != current {
+                current = latest;
+                quiet_since = Instant::now();
+            }
+        }
+
+        let changed: Vec<String> = changed_paths(&last_run, &current).iter()
+            .map(|path| path.strip_prefix(root).unwrap_or(path).display().to_string())
+            .collect();
+        info!("Changed: {}", changed.join(", "));
+
+        answers = run(root, watch_args, &answers);
+        last_run = snapshot(&watched_paths(root, &day_dir, watch_args));
+    }
+}
+
+// runs the day and prints its answers next to the previous ones, returning the new answers
+// a failed run keeps the previous answers, so the next good run is compared with the last good one
+fn run (root: &Path, watch_args: &WatchArgs, previous: &[(u32, String)]) -> Vec<(u32, String)> {
+    let mut command = Command::new(std::env::var("CARGO").unwrap_or("cargo".to_string()));
+    command.args(["run", "--quiet", "--package", &format!("day{}", watch_args.day)])
+        .arg("--manifest-path").arg(root.join("Cargo.toml"));
+    if watch_args.release {
+        command.arg("--release");
+    }
+
+    command.arg("--");
+    match &watch_args.input {
+        InputSource::Example => {
+            command.arg(EXAMPLE_ARG);
+        },
+        InputSource::Path(path) => {
+            command.arg(path);
+        },
+        // stdin can't be read more than once, so it isn't allowed here
+        InputSource::Default | InputSource::Stdin => {},
+    }
+    match watch_args.log_level {
+        Level::Debug => {
+            command.arg(VERBOSE_ARG);
+        },
+        Level::Trace => {
+            command.arg(VERY_VERBOSE_ARG);
+        },
+        Level::Error | Level::Info => {},
+    }
+
+    // build errors and the day's own diagnostics go straight through, only the answers get read
+    let output = match command.stderr(Stdio::inherit()).output() {
+        Ok(output) => output,
+        Err(err) => {
+            error!("unable to run cargo: {}", err);
+            return previous.to_vec();
+        }
+    };
+    if !output.status.success() {
+        error!("day {} failed ({}), waiting for another change", watch_args.day, output.status);
+        return previous.to_vec();
+    }
+
+    let answers = parse_answers(&String::from_utf8_lossy(&output.stdout));
+
+    for (part, answer) in answers.iter() {
+        match previous.iter().find(|(previous_part, _)| previous_part == part) {
+            Some((_, previous_answer)) if previous_answer == answer => println!("Part {}: {} (unchanged)", part, answer),
+            Some((_, previous_answer)) => println!("Part {}: {} (was {})", part, answer, previous_answer),
+            None => println!("Part {}: {}", part, answer),
+        }
+    }
+
+    return answers;
+}
+
+// the `Part N: answer` lines a day prints
+fn parse_answers (stdout: &str) -> Vec<(u32, String)> {
+    let mut answers = vec![];
+
+    for line in stdout.lines() {
+        let answer = line.strip_prefix("Part ")
+            .and_then(|rest| rest.split_once(": "))
+            .and_then(|(part, answer)| Some((part.parse::<u32>().ok()?, answer.to_string())));
+
+        if let Some(answer) = answer {
+            answers.push(answer);
+        }
+    }
+
+    return answers;
+}
+
+// the day's code and the shared code it builds on, its input and examples, and the binary
+// `cargo run` builds, which also changes when something else rebuilds the day
+fn watched_paths (root: &Path, day_dir: &Path, watch_args: &WatchArgs) -> Vec<PathBuf> {
+    let mut paths = vec![day_dir.join("Cargo.toml")];
+
+    source_files(&day_dir.join("src"), &mut paths);
+    source_files(&root.join("aoc-common").join("src"), &mut paths);
+
+    if let Some(input) = watch_args.input.path(day_dir) {
+        paths.push(input);
+    }
+    for example in testing::example_files(day_dir) {
+        paths.push(example.with_extension(testing::ANSWERS_EXTENSION));
+        paths.push(example);
+    }
+
+    let target_dir = std::env::var_os("CARGO_TARGET_DIR").map(PathBuf::from).unwrap_or(root.join("target"));
+    let profile = if watch_args.release { "release" } else { "debug" };
+    paths.push(target_dir.join(profile).join(format!("day{}", watch_args.day)));
+
+    return paths;
+}
+
+fn source_files (dir: &Path, paths: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            source_files(&path, paths);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            paths.push(path);
+        }
+    }
+}
+
+// files that don't exist are left out, so one appearing or going away counts as a change
+fn snapshot (paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+
+    for path in paths {
+        if let Ok(modified) = fs::metadata(path).and_then(|metadata| metadata.modified()) {
+            snapshot.insert(path.clone(), modified);
+        }
+    }
+
+    return snapshot;
+}
+
+fn changed_paths (before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after.iter()
+        .filter(|(path, modified)| before.get(*path) != Some(*modified))
+        .map(|(path, _)| path.clone())
+        .collect();
+
+    for path in before.keys() {
+        if !after.contains_key(path) {
+            changed.push(path.clone());
+        }
+    }
+
+    return changed;
+}
@@ -0,0 +1,56 @@
+// the answers file is only used by the aoc binary, so its module is pulled in directly
+
+use std::fs;
+use std::path::Path;
+
+use aoc_common::Answer;
+
+#[path = "../src/answers.rs"]
+#[allow(dead_code)]
+mod answers;
+
+use answers::AnswerStore;
+
+fn store () -> AnswerStore {
+    let mut store = AnswerStore::default();
+    store.set(1, &answers::input_hash("L68\n"), 1, Answer::Number(-3));
+    store.set(1, &answers::input_hash("L68\n"), 2, Answer::Text("a \"quoted\" \\ answer".to_string()));
+    store.set(12, "00ff", 1, Answer::Number(i64::MAX));
+    return store;
+}
+
+#[test]
+fn saved_answers_load_back_the_same () {
+    let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+
+    store().save(&path).unwrap();
+    let loaded = AnswerStore::load(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded, Ok(store()));
+    assert_eq!(AnswerStore::parse(&store().to_toml()), Ok(store()));
+    // nothing recorded yet isn't an error
+    assert_eq!(AnswerStore::load(Path::new("/nonexistent/answers.toml")), Ok(AnswerStore::default()));
+}
+
+#[test]
+fn strings_are_unescaped () {
+    let store = AnswerStore::parse("[day3.abc]\npart1 = \"say \\\"hi\\\" \\\\o/\"\npart2 = \"\"\n").unwrap();
+
+    assert_eq!(store.get(3, "abc", 1), Some(&Answer::Text("say \"hi\" \\o/".to_string())));
+    assert_eq!(store.get(3, "abc", 2), Some(&Answer::Text("".to_string())));
+
+    // only quotes and backslashes are escaped, and a bare quote ends the string early
+    assert_eq!(AnswerStore::parse("[day3.abc]\npart1 = \"\\n\"\n"), Err("2: expected a number or a string, found \"\\n\"".to_string()));
+    assert_eq!(AnswerStore::parse("[day3.abc]\npart1 = \"a\"b\"\n"), Err("2: expected a number or a string, found \"a\"b\"".to_string()));
+}
+
+#[test]
+fn malformed_lines_are_errors () {
+    assert_eq!(AnswerStore::parse("# comment\n[day1]\npart1 = 3\n"), Err("2: expected [dayN.hash], found [day1]".to_string()));
+    assert_eq!(AnswerStore::parse("[dayx.abc]\n"), Err("1: expected [dayN.hash], found [dayx.abc]".to_string()));
+    assert_eq!(AnswerStore::parse("[day1.xyz]\n"), Err("1: expected [dayN.hash], found [day1.xyz]".to_string()));
+    assert_eq!(AnswerStore::parse("\npart1 = 3\n"), Err("2: expected a [dayN.hash] table before any answers".to_string()));
+    assert_eq!(AnswerStore::parse("[day1.abc]\npart1 3\n"), Err("2: expected partN = answer, found part1 3".to_string()));
+    assert_eq!(AnswerStore::parse("[day1.abc]\nanswer = 3\n"), Err("2: expected part1 or part2, found answer".to_string()));
+}
@@ -0,0 +1,90 @@
+// runs `aoc fetch` against a local stand-in for the puzzle site
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const INPUT: &str = "L68\nR48\n";
+const TOKEN: &str = "test-session";
+
+// answers every request with `status` and `body`, keeping each request it saw
+fn stub_server (status: &'static str, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(vec![]));
+    let seen = requests.clone();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = vec![];
+            let mut buffer = [0; 1024];
+
+            while !request.ends_with(b"\r\n\r\n") {
+                let n_read = stream.read(&mut buffer).unwrap();
+                if n_read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..n_read]);
+            }
+            seen.lock().unwrap().push(String::from_utf8_lossy(&request).to_string());
+
+            // chunked, the way the real site sends inputs
+            let response = format!("HTTP/1.1 {}\r\nTransfer-Encoding: chunked\r\n\r\n{:x}\r\n{}\r\n0\r\n\r\n", status, body.len(), body);
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    return (base_url, requests);
+}
+
+fn cache_dir (name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    return dir;
+}
+
+fn fetch (base_url: &str, cache_dir: &PathBuf) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fetch", "--day", "1", "--year", "2025", "--base-url", base_url])
+        .env("AOC_SESSION", TOKEN)
+        .env("AOC_CACHE_DIR", cache_dir)
+        .output()
+        .unwrap();
+}
+
+#[test]
+fn downloads_once_then_uses_the_cache () {
+    let (base_url, requests) = stub_server("200 OK", INPUT);
+    let cache_dir = cache_dir("cache");
+
+    let first = fetch(&base_url, &cache_dir);
+    assert!(first.status.success(), "{}", String::from_utf8_lossy(&first.stderr));
+    assert_eq!(fs::read_to_string(cache_dir.join("day1.txt")).unwrap(), INPUT);
+
+    let second = fetch(&base_url, &cache_dir);
+    assert!(second.status.success(), "{}", String::from_utf8_lossy(&second.stderr));
+    assert!(String::from_utf8_lossy(&second.stdout).contains("already cached"));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1, "cached input was fetched again");
+    assert!(requests[0].starts_with("GET /2025/day/1/input HTTP/1.1\r\n"), "{}", requests[0]);
+    assert!(requests[0].contains(&format!("Cookie: session={}\r\n", TOKEN)), "{}", requests[0]);
+
+    fs::remove_dir_all(&cache_dir).unwrap();
+}
+
+#[test]
+fn errors_are_not_cached () {
+    let (base_url, _requests) = stub_server("404 Not Found", "not found");
+    let cache_dir = cache_dir("error");
+
+    let output = fetch(&base_url, &cache_dir);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("404"));
+    assert!(!cache_dir.join("day1.txt").exists());
+}
@@ -0,0 +1,42 @@
+// a CSV of every rotation, for finding out which ones pointed the dial at 0 when a password's wrong
+// rows are written out as the dial turns, so the trace never has to fit in memory
+
+use std::io::{self, Write};
+
+use crate::{Instructions, Passwords};
+
+pub const HEADER: &str = "index,instruction,position_before,position_after,part1_zeros,part2_zeros";
+
+// turns the dial through every step writing a row for each, and gives back the passwords
+// `input` has to be what `instructions` were parsed from, for the text of each instruction
+pub fn write (input: &str, instructions: &Instructions, out: &mut impl Write) -> io::Result<Passwords> {
+    let mut dial = instructions.dial;
+    let mut passwords = Passwords::default();
+
+    writeln!(out, "{}", HEADER)?;
+
+    for (index, (step, source)) in instructions.steps.iter().zip(instructions.sources.iter()).enumerate() {
+        let position_before = dial.position();
+        let clicks = dial.apply(*step);
+        passwords.count(&dial, clicks);
+
+        // part 1 only counts stopping on 0, part 2 counts every time it's passed as well
+        let stopped_on_zero = if dial.position() == 0 { 1 } else { 0 };
+
+        // 1-based, like line numbers
+        write!(out, "{},", index + 1)?;
+        write_field(out, &input[source.clone()])?;
+        writeln!(out, ",{},{},{},{}", position_before, dial.position(), stopped_on_zero, clicks)?;
+    }
+
+    out.flush()?;
+    return Ok(passwords);
+}
+
+// quoted if there's anything in it that would confuse a CSV reader
+fn write_field (out: &mut impl Write, text: &str) -> io::Result<()> {
+    if text.contains([',', '"', '\n', '\r']) {
+        return write!(out, "\"{}\"", text.replace('"', "\"\""));
+    }
+    return write!(out, "{}", text);
+}
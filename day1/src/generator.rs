@@ -0,0 +1,48 @@
+// random rotations for the dial, `size` of them
+
+use aoc_common::random::Rng;
+
+use crate::{COMMENT_CHAR, DIAL_SIZE};
+
+// the real input never turns more than this in one go
+const MAX_DISTANCE: i64 = 999;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+    let mut dial_size = DIAL_SIZE;
+
+    if rng.chance(0.1) {
+        lines.push(format!("{} generated", COMMENT_CHAR));
+    }
+
+    // now and then a dial other than the puzzle's, big or small
+    if rng.chance(0.3) {
+        dial_size = rng.range(1, 1000);
+        lines.push(format!("dial {}", dial_size));
+        lines.push(format!("start {}", rng.range(0, dial_size - 1)));
+    }
+
+    // an empty file parses fine but turns nothing, so there's always at least one line to check
+    for _ in 0..size.max(1) {
+        // the odd jump straight to a position or a short repeated run among the plain rotations
+        let line = if rng.chance(0.05) {
+            format!("={}", rng.range(0, dial_size - 1))
+        } else if rng.chance(0.05) {
+            format!("{}x({} {})", rng.range(1, 3), rotation(rng), rotation(rng))
+        } else {
+            rotation(rng)
+        };
+
+        lines.push(line);
+    }
+
+    return lines.join("\n") + "\n";
+}
+
+fn rotation (rng: &mut Rng) -> String {
+    let direction = if rng.chance(0.5) { 'L' } else { 'R' };
+    // mostly less than a full turn, with the odd long spin to wrap round a few times
+    let distance = if rng.chance(0.8) { rng.range(1, 99) } else { rng.range(100, MAX_DISTANCE) };
+
+    return format!("{}{}", direction, distance);
+}
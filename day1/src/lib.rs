@@ -0,0 +1,309 @@
+use std::ops::Range;
+use std::str::FromStr;
+
+use aoc_common::{parse::{self, Span}, solution, trace, Answer, ParseError, Solution};
+
+pub mod csv_trace;
+pub mod generator;
+pub mod oracle;
+pub mod sweep;
+
+// the puzzle's dial, inputs can ask for a different one with `dial <size>` and `start <position>` lines
+const DIAL_SIZE: i64 = 100;
+const START: i64 = 50;
+// leaves room to turn any distance from anywhere on the dial without overflowing
+const MAX_DIAL_SIZE: i64 = i64::MAX / 2;
+
+// everything from here to the end of the line is ignored
+const COMMENT_CHAR: char = '#';
+const INSTRUCTION: &str = "a rotation like R10 or L5, a position like =37 or a repeat like 3x(R10 L5)";
+// the most steps the instructions can come to once repeats are written out
+const MAX_STEPS: usize = 1_000_000;
+// how many repeats can be open at once, as each one is parsed a level further down the stack
+const MAX_NESTING: usize = 100;
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day1>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day1>(input);
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    type Input = Instructions;
+
+    fn parse (input: &str) -> Result<Instructions, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1 (instructions: &Instructions) -> Answer {
+        return passwords(instructions.dial, &instructions.steps).first.into();
+    }
+
+    fn part2 (instructions: &Instructions) -> Answer {
+        return passwords(instructions.dial, &instructions.steps).second.into();
+    }
+}
+
+pub struct Instructions {
+    // where the dial starts
+    pub dial: Dial,
+    // with repeats written out, positions are kept as they are since they depend on where the dial's got to
+    pub steps: Vec<Step>,
+    // where in the input each step came from, as byte offsets
+    pub sources: Vec<Range<usize>>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    // right is positive, left negative
+    Turn(i64),
+    // straight to a position on the dial, without going round past 0
+    Set(i64)
+}
+
+impl Step {
+    // how far `dial` has to turn to carry out this step from where it is
+    pub fn delta (&self, dial: &Dial) -> i64 {
+        return match self {
+            Step::Turn(delta) => *delta,
+            Step::Set(position) => position - dial.position,
+        }
+    }
+}
+
+// a dial numbered from 0 round to size - 1, turning right counts up and turning left counts down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    size: i64,
+    position: i64
+}
+
+// how many times a rotation pointed the dial at 0, counting where it stopped but not where it started
+pub type ClicksPassed = i64;
+
+impl Dial {
+    // the position has to be on the dial
+    pub fn new (size: i64, position: i64) -> Result<Dial, String> {
+        if !(1..=MAX_DIAL_SIZE).contains(&size) {
+            return Err(format!("a dial size from 1 to {}", MAX_DIAL_SIZE));
+        }
+        if position < 0 || position >= size {
+            return Err(format!("a position from 0 to {} on a dial of size {}", size - 1, size));
+        }
+        return Ok(Dial { size: size, position: position });
+    }
+
+    pub fn size (&self) -> i64 {
+        return self.size;
+    }
+
+    pub fn position (&self) -> i64 {
+        return self.position;
+    }
+
+    pub fn rotate (&mut self, delta: i64) -> ClicksPassed {
+        let start = self.position;
+        let end = start + delta;
+        self.position = end.rem_euclid(self.size);
+
+        // 0 comes round at every multiple of the size between start and end, so count those,
+        // leaving out start and keeping end whichever way we turned
+        if delta >= 0 {
+            return end.div_euclid(self.size) - start.div_euclid(self.size);
+        }
+        return (start - 1).div_euclid(self.size) - (end - 1).div_euclid(self.size);
+    }
+
+    pub fn apply (&mut self, step: Step) -> ClicksPassed {
+        return self.rotate(step.delta(self));
+    }
+}
+
+// both passwords, worked out together from a single pass over the rotations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Passwords {
+    // how many rotations left the dial at 0
+    pub first: i64,
+    // how many times the dial pointed at 0 at all, including on the way past
+    pub second: i64
+}
+
+impl Passwords {
+    // adds on a rotation that's just left `dial` where it is
+    pub fn count (&mut self, dial: &Dial, clicks: ClicksPassed) {
+        if dial.position == 0 {
+            self.first += 1;
+        }
+        self.second += clicks;
+    }
+}
+
+pub fn passwords (dial: Dial, steps: &[Step]) -> Passwords {
+    let mut dial = dial;
+    let mut passwords = Passwords::default();
+
+    for step in steps {
+        let position_before = dial.position;
+        let clicks = dial.apply(*step);
+        passwords.count(&dial, clicks);
+
+        trace!("before: {} | step: {:?} | after: {} | clicks: {}", position_before, step, dial.position, clicks);
+    }
+
+    return passwords;
+}
+
+// besides the puzzle's R10 and L5, the dial can be turned straight to a position with =37 and
+// instructions can be repeated with 3x(R10 L5), which can go inside each other but not over lines
+// they all end up as a list of steps, along with where in the input each one came from
+fn parse_input (input: &str) -> Result<Instructions, ParseError> {
+    let mut size = DIAL_SIZE;
+    let mut start = START;
+    // where each was set, if it was, for pointing out a dial that doesn't make sense
+    let mut size_setting = None;
+    let mut start_setting = None;
+    // set up at the first instruction
+    let mut dial = None;
+    let mut steps = vec![];
+    let mut sources = vec![];
+
+    for line in parse::lines(input) {
+        let line = match line.text.find(COMMENT_CHAR) {
+            Some(comment_idx) => line.slice(..comment_idx).trim(),
+            None => line.trim(),
+        };
+        if line.is_empty() {
+            continue;
+        }
+
+        let setting = line.words()[0];
+        if setting.text == "dial" || setting.text == "start" {
+            // the dial can only be set up before it's turned
+            if dial.is_some() {
+                return Err(setting.error(INSTRUCTION));
+            }
+
+            let (_, value) = line.split_once(" ", "a setting like dial 60")?;
+            let value = value.trim();
+            if setting.text == "dial" {
+                size = value.parse::<i64>("a dial size")?;
+                // 0 is on every dial, so this only checks the size
+                Dial::new(size, 0).map_err(|message| value.error(message))?;
+                size_setting = Some(value);
+            } else {
+                start = value.parse::<i64>("a start position")?;
+                start_setting = Some(value);
+            }
+            continue;
+        }
+
+        if dial.is_none() {
+            dial = Some(new_dial(size, start, size_setting, start_setting)?);
+        }
+
+        let mut line_steps = vec![];
+        let rest = parse_steps(line, &mut line_steps, MAX_STEPS.saturating_sub(steps.len()), 0)?;
+        if !rest.is_empty() {
+            // the only thing that stops the steps early is a ')' without a repeat to close
+            return Err(rest.slice(..1).error(INSTRUCTION));
+        }
+
+        for (step, step_text) in line_steps {
+            // on the dial whichever position it starts from, as the size can't change
+            if let Step::Set(position) = step {
+                if position >= size {
+                    return Err(step_text.error(format!("a position from 0 to {}", size - 1)));
+                }
+            }
+
+            steps.push(step);
+            sources.push(source(input, step_text.text));
+        }
+    }
+
+    let dial = match dial {
+        Some(dial) => dial,
+        None => new_dial(size, start, size_setting, start_setting)?,
+    };
+    return Ok(Instructions { dial: dial, steps: steps, sources: sources });
+}
+
+// the size has already been checked, so anything wrong is the start not being on the dial,
+// which is down to the start setting if there is one and otherwise the size setting
+fn new_dial (size: i64, start: i64, size_setting: Option<Span>, start_setting: Option<Span>) -> Result<Dial, ParseError> {
+    return Dial::new(size, start).map_err(|message| {
+        let value = start_setting.or(size_setting).expect("the default start is on the default dial");
+        return value.error(message);
+    });
+}
+
+// the steps in `text` with repeats written out, stopping at the end or at a ')' closing a repeat,
+// and handing back whatever's left
+// repeats can't take `steps` past `limit`, so a big count is an error rather than running out of memory,
+// and `depth` is how many repeats `text` is already inside, so deep nesting can't run out of stack either
+fn parse_steps<'a> (text: Span<'a>, steps: &mut Vec<(Step, Span<'a>)>, limit: usize, depth: usize) -> Result<Span<'a>, ParseError> {
+    let mut rest = text.trim_start();
+
+    while !rest.is_empty() && !rest.text.starts_with(')') {
+        let token_end = rest.text.find(|c: char| c.is_whitespace() || c == '(' || c == ')').unwrap_or(rest.text.len());
+        let token = rest.slice(..token_end);
+        let after = rest.slice(token_end..);
+
+        match token.split_first() {
+            // the direction says which way, so distances don't have a sign
+            Some(('R', distance)) => steps.push((Step::Turn(unsigned::<u32>(distance, "a distance")? as i64), token)),
+            Some(('L', distance)) => steps.push((Step::Turn(-(unsigned::<u32>(distance, "a distance")? as i64)), token)),
+            Some(('=', position)) => steps.push((Step::Set(unsigned::<i64>(position, "a position")?), token)),
+            _ if token.text.ends_with('x') && after.text.starts_with('(') => {
+                let count = token.slice(..(token.text.len() - 1));
+                let times = unsigned::<usize>(count, "a number of repeats")?;
+                if depth >= MAX_NESTING {
+                    return Err(after.slice(..1).error(format!("repeats nested no more than {} deep", MAX_NESTING)));
+                }
+
+                let mut repeated = vec![];
+                let after = parse_steps(after.slice(1..), &mut repeated, limit.saturating_sub(steps.len()), depth + 1)?;
+                if !after.text.starts_with(')') {
+                    return Err(after.error_after("')' to close the repeat"));
+                }
+
+                let total = repeated.len().checked_mul(times).and_then(|n_repeated| n_repeated.checked_add(steps.len()));
+                if total.is_none_or(|total| total > limit) {
+                    return Err(count.error(format!("few enough repeats to keep to {} steps in all", MAX_STEPS)));
+                }
+
+                for _ in 0..times {
+                    steps.extend_from_slice(&repeated);
+                }
+                rest = after.slice(1..).trim_start();
+                continue;
+            },
+            // a '(' with no count in front
+            _ if token.is_empty() => return Err(after.slice(..1).error(INSTRUCTION)),
+            _ => return Err(token.error(INSTRUCTION)),
+        }
+
+        rest = after.trim_start();
+    }
+
+    return Ok(rest);
+}
+
+// just digits, no sign
+fn unsigned<T: FromStr> (text: Span, expected: &str) -> Result<T, ParseError> {
+    if text.is_empty() || !text.text.chars().all(|c| c.is_ascii_digit()) {
+        return Err(text.error(expected));
+    }
+    return text.parse::<T>(expected);
+}
+
+// where `text` is within `input`, which it has to be a slice of
+fn source (input: &str, text: &str) -> Range<usize> {
+    let start = text.as_ptr() as usize - input.as_ptr() as usize;
+    return start..(start + text.len());
+}
\ No newline at end of file
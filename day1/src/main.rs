@@ -1,91 +1,176 @@
-use std::{fs};
-use std::time::{SystemTime};
-
-const FILENAME: &str = "./input.txt";
-const START: i32 = 50;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc_common::{args::{DayArgs, OutputFormat}, error, info, json::Json, log, report, solution, Solution};
+use day1::{csv_trace, sweep::{self, Extremes}, Day1, Instructions, Passwords};
+
+aoc_common::count_allocations!();
+
+// work out the passwords from every start position instead of just the input's
+const SWEEP_ARG: &str = "--sweep";
+// write every rotation out to a CSV file while working out the passwords
+const TRACE_ARG: &str = "--trace";
+// a dial is kept for every start when sweeping, so there's a limit on how many starts there can be
+const MAX_SWEEP_SIZE: i64 = 100_000;
+const DAY1_USAGE: &str = "       day1 [--sweep | --trace <path>] [--example | <path> | -] [--format <human|json>] [-v | -vv]";
+
+// what day1 can do on top of what every day does
+enum Mode {
+    Solve,
+    Sweep,
+    Trace(PathBuf)
+}
 
-fn main() {
-    let start = SystemTime::now();
-    let instructions = parse_input();
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
-    println!("{}", (-100000 % 100));
+    let (mode, rest) = match parse_mode(&args) {
+        Ok(parsed) => parsed,
+        Err(err) => return usage_error(&err),
+    };
+    if let Mode::Solve = mode {
+        return solution::main::<Day1>(env!("CARGO_MANIFEST_DIR"));
+    }
 
-    let password_1 = first_password(&instructions);
-    let password_2 = second_password(&instructions);
-    
-    println!("The first password is: {}", password_1);
-    println!("The second password is: {}", password_2);
+    let day_args = match DayArgs::parse(&rest) {
+        Ok(day_args) => day_args,
+        Err(err) => return usage_error(&err),
+    };
+    if day_args.bench.is_some() || day_args.memory {
+        return usage_error(&format!("{} and {} can't be used with --bench or --memory", SWEEP_ARG, TRACE_ARG));
+    }
 
-    let end = SystemTime::now();
-    println!("Program ran in {}ms", end.duration_since(start).unwrap().subsec_millis());
+    log::set_level(day_args.log_level);
+
+    let day_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let input_name = day_args.input.name(day_dir);
+    let input = match day_args.input.read(day_dir) {
+        Ok(input) => input,
+        Err(err) => return report::read_error(&input_name, &err),
+    };
+    let instructions = match Day1::parse(&input) {
+        Ok(instructions) => instructions,
+        Err(err) => return report::exit_code::<()>(&Err(err), &input_name),
+    };
+
+    return match mode {
+        Mode::Solve => unreachable!("solving is left to the usual runner"),
+        Mode::Sweep => sweep(&instructions, &input_name, day_args.format),
+        Mode::Trace(path) => trace(&input, &instructions, &input_name, &path, day_args.format),
+    }
 }
 
-fn first_password (instructions: &Vec<i32>) -> i32 {
-    let mut position = START;
-    let mut password = 0;
-
-    for i in instructions {
-        position += i;
-        position = (position + 100) % 100;
-        // println!("{}", position);
-
-        if position == 0 {
-            password += 1;
+// the day's own flags, and everything else for `DayArgs`
+fn parse_mode (args: &[String]) -> Result<(Mode, Vec<String>), String> {
+    let mut mode = Mode::Solve;
+    let mut rest = vec![];
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let next_mode = match arg.as_str() {
+            SWEEP_ARG => Mode::Sweep,
+            TRACE_ARG => Mode::Trace(PathBuf::from(args.next().ok_or(format!("{} needs a path", TRACE_ARG))?)),
+            _ => {
+                rest.push(arg.clone());
+                continue;
+            }
+        };
+
+        if let Mode::Solve = mode {
+            mode = next_mode;
+        } else {
+            return Err(format!("expected only one of {} or {}", SWEEP_ARG, TRACE_ARG));
         }
     }
-    
-    return password
+
+    return Ok((mode, rest));
 }
 
-fn second_password (instructions: &Vec<i32>) -> i32 {
-    let mut password = 0;
+fn usage_error (err: &str) -> ExitCode {
+    eprintln!("{}", err);
+    eprintln!("{}", solution::USAGE);
+    eprintln!("{}", DAY1_USAGE);
+    return ExitCode::FAILURE;
+}
 
-    let mut position_before = START;
+fn sweep (instructions: &Instructions, input_name: &str, format: OutputFormat) -> ExitCode {
+    let size = instructions.dial.size();
+    if size > MAX_SWEEP_SIZE {
+        return usage_error(&format!("{} only works on dials of up to {} positions, not {}", SWEEP_ARG, MAX_SWEEP_SIZE, size));
+    }
 
-    for i in instructions {
-        let position_after = position_before + i;
-        let clicks ;
+    let swept = sweep::sweep(size, &instructions.steps);
+    let (first, second) = match (sweep::extremes(&swept, |passwords| passwords.first), sweep::extremes(&swept, |passwords| passwords.second)) {
+        (Some(first), Some(second)) => (first, second),
+        _ => unreachable!("every dial has at least one start"),
+    };
 
-        if position_before != 0 && position_after == 0 {
-            clicks = 1;
-        } else if position_before > 0 && position_after < 0 {
-            clicks = (position_after * -1) / 100 + 1;
-        } else if position_after >= 100 {
-            clicks = position_after / 100;
-        } else if position_after < -100 {
-            clicks = (position_after * -1) / 100;
-        } else {
-            clicks = 0;
-        }
-        password += clicks;
+    match format {
+        OutputFormat::Human => print_sweep(&swept, &first, &second),
+        OutputFormat::Json => println!("{}", sweep_json(input_name, &swept, &first, &second)),
+    }
 
+    return ExitCode::SUCCESS;
+}
 
-        // println!("before: {} | move: {} | after: {} | clicks: {}", position_before, i, position_after, clicks);
-    
-        position_before = position_after.rem_euclid(100);
+fn print_sweep (swept: &[Passwords], first: &Extremes, second: &Extremes) {
+    println!("{:>5} | {:>10} | {:>10}", "Start", "Part 1", "Part 2");
+    println!("{}", "-".repeat(31));
+    for (start, passwords) in swept.iter().enumerate() {
+        println!("{:>5} | {:>10} | {:>10}", start, passwords.first, passwords.second);
     }
 
-    return password;
+    println!();
+    for (part, extremes) in [(1, first), (2, second)] {
+        println!(
+            "Part {}: best {} from start {}, worst {} from start {}",
+            part, extremes.best.1, extremes.best.0, extremes.worst.1, extremes.worst.0
+        );
+    }
+}
+
+fn sweep_json (input_name: &str, swept: &[Passwords], first: &Extremes, second: &Extremes) -> Json {
+    let starts: Vec<Json> = swept.iter().enumerate()
+        .map(|(start, passwords)| Json::object().with("start", start).with("part1", passwords.first).with("part2", passwords.second))
+        .collect();
+
+    let extremes_json = |extremes: &Extremes| {
+        return Json::object()
+            .with("best", Json::object().with("start", extremes.best.0).with("password", extremes.best.1))
+            .with("worst", Json::object().with("start", extremes.worst.0).with("password", extremes.worst.1));
+    };
+
+    return Json::object()
+        .with("day", Day1::DAY)
+        .with("input", input_name)
+        .with("starts", starts)
+        .with("part1", extremes_json(first))
+        .with("part2", extremes_json(second));
 }
 
-fn parse_input () -> Vec<i32> {
-    let mut instructions = vec![];
-
-    for line in fs::read_to_string(FILENAME).unwrap().lines() {
-        let direction_char = line.chars().next();
-        let distance = line[1..].parse::<i32>().unwrap();
-
-        match direction_char {
-            Some('R') => {
-                instructions.push(distance);
-            },
-            Some('L') => {
-                instructions.push(-1 * distance);
-            },
-            Some(_) => panic!("oh no"),
-            None => panic!("oh no"),
+fn trace (input: &str, instructions: &Instructions, input_name: &str, path: &Path, format: OutputFormat) -> ExitCode {
+    let written = File::create(path).and_then(|file| csv_trace::write(input, instructions, &mut BufWriter::new(file)));
+    let passwords = match written {
+        Ok(passwords) => passwords,
+        Err(err) => {
+            error!("unable to write {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
         }
+    };
+
+    match format {
+        OutputFormat::Human => {
+            report::answer(1, passwords.first);
+            report::answer(2, passwords.second);
+            info!("Trace written to {}", path.display());
+        },
+        OutputFormat::Json => {
+            let answers = Json::object().with("part1", passwords.first).with("part2", passwords.second);
+            println!("{}", Json::object().with("day", Day1::DAY).with("input", input_name).with("answers", answers).with("trace", path.display().to_string()));
+        },
     }
 
-    return instructions
-}
\ No newline at end of file
+    return ExitCode::SUCCESS;
+}
@@ -0,0 +1,68 @@
+// a reference for `Dial::rotate` that turns the dial one click at a time, far too slow for the
+// real input but simple enough to be obviously right, so the closed form can be checked against it
+
+use std::fmt::{Display, Formatter};
+
+use crate::{ClicksPassed, Dial, Step};
+
+pub fn rotate_by_clicks (dial: &mut Dial, delta: i64) -> ClicksPassed {
+    let mut clicks = 0;
+
+    for _ in 0..delta.abs() {
+        dial.position = (dial.position + delta.signum()).rem_euclid(dial.size);
+        if dial.position == 0 {
+            clicks += 1;
+        }
+    }
+
+    return clicks;
+}
+
+// the first rotation `Dial::rotate` and the oracle disagree on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    // 0-based
+    pub index: usize,
+    // how far the step turned the dial
+    pub rotation: i64,
+    pub position_before: i64,
+    // the position after and the clicks passed, from each of them
+    pub rotated: (i64, ClicksPassed),
+    pub stepped: (i64, ClicksPassed)
+}
+
+impl Display for Divergence {
+    fn fmt (&self, f: &mut Formatter) -> std::fmt::Result {
+        let direction = if self.rotation < 0 { 'L' } else { 'R' };
+        write!(
+            f, "rotation {} ({}{}) from {}: rotate ended at {} passing 0 {} times, stepping ended at {} passing 0 {} times",
+            self.index + 1, direction, self.rotation.abs(), self.position_before, self.rotated.0, self.rotated.1, self.stepped.0, self.stepped.1
+        )
+    }
+}
+
+// turns two copies of the dial side by side, one each way
+pub fn first_divergence (dial: Dial, steps: &[Step]) -> Option<Divergence> {
+    let mut rotated = dial;
+    let mut stepped = dial;
+
+    for (index, step) in steps.iter().enumerate() {
+        // they've agreed so far, so they're both starting from the same place
+        let position_before = rotated.position;
+        let rotation = step.delta(&rotated);
+        let rotated_clicks = rotated.rotate(rotation);
+        let stepped_clicks = rotate_by_clicks(&mut stepped, rotation);
+
+        if rotated != stepped || rotated_clicks != stepped_clicks {
+            return Some(Divergence {
+                index: index,
+                rotation: rotation,
+                position_before: position_before,
+                rotated: (rotated.position, rotated_clicks),
+                stepped: (stepped.position, stepped_clicks)
+            });
+        }
+    }
+
+    return None;
+}
@@ -0,0 +1,45 @@
+// how much the passwords depend on where the dial starts, found by turning a dial from every
+// start position at once, so the rotations are only gone through the once
+
+use crate::{Dial, Passwords, Step};
+
+// the passwords from each start on a dial of `size`, indexed by start
+pub fn sweep (size: i64, steps: &[Step]) -> Vec<Passwords> {
+    let mut dials: Vec<Dial> = (0..size).map(|start| Dial { size: size, position: start }).collect();
+    let mut passwords = vec![Passwords::default(); dials.len()];
+
+    for step in steps {
+        for (dial, passwords) in dials.iter_mut().zip(passwords.iter_mut()) {
+            let clicks = dial.apply(*step);
+            passwords.count(dial, clicks);
+        }
+    }
+
+    return passwords;
+}
+
+// the starts giving the highest and lowest of one of the passwords, as (start, password)
+// ties go to the lowest start
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Extremes {
+    pub best: (i64, i64),
+    pub worst: (i64, i64)
+}
+
+// None if nothing was swept
+pub fn extremes (swept: &[Passwords], password: fn(&Passwords) -> i64) -> Option<Extremes> {
+    let mut best = (0, password(swept.first()?));
+    let mut worst = best;
+
+    for (start, passwords) in swept.iter().enumerate() {
+        let value = password(passwords);
+        if value > best.1 {
+            best = (start as i64, value);
+        }
+        if value < worst.1 {
+            worst = (start as i64, value);
+        }
+    }
+
+    return Some(Extremes { best: best, worst: worst });
+}
@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day1::{csv_trace, passwords, Day1};
+
+#[test]
+fn trace_adds_up_to_the_passwords () {
+    let input = "dial 60\nstart 30\nL68\nL30\nR8\n";
+    let instructions = Day1::parse(input).unwrap();
+
+    let mut out = vec![];
+    let traced = csv_trace::write(input, &instructions, &mut out).unwrap();
+    let trace = String::from_utf8(out).unwrap();
+
+    let lines: Vec<&str> = trace.lines().collect();
+    assert_eq!(lines, vec![csv_trace::HEADER, "1,L68,30,22,0,1", "2,L30,22,52,0,1", "3,R8,52,0,1,1"]);
+    assert_eq!(traced, passwords(instructions.dial, &instructions.steps));
+}
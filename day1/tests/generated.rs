@@ -0,0 +1,7 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    // every time the dial stops on 0 it also passes 0
+    testing::check_generated::<day1::Day1>(day1::generator::generate, &[1, 10, 100], |part1, part2| part2.as_number() >= part1.as_number());
+}
@@ -0,0 +1,70 @@
+use aoc_common::Solution;
+use day1::{passwords, Day1, Step};
+
+#[test]
+fn repeats_are_written_out_and_positions_kept () {
+    let instructions = Day1::parse("start 10\n2x(R5 2x(L1)) # round\n\n=0 =99\n").unwrap();
+
+    let turns = [Step::Turn(5), Step::Turn(-1), Step::Turn(-1)];
+    assert_eq!(instructions.steps, [&turns[..], &turns[..], &[Step::Set(0), Step::Set(99)]].concat());
+}
+
+#[test]
+fn positions_past_i32_stay_exact () {
+    let instructions = Day1::parse("dial 10000000000\nstart 0\n=4294967296\n").unwrap();
+    let passwords = passwords(instructions.dial, &instructions.steps);
+    assert_eq!((passwords.first, passwords.second), (0, 0));
+}
+
+#[test]
+fn errors_point_at_the_offending_instruction () {
+    let error = Day1::parse("R10\n2x(L5 R3\n").err().unwrap();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 9, "end of line"));
+
+    let error = Day1::parse("R10\nL5)\n").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 3));
+
+    let error = Day1::parse("=100\n").err().unwrap();
+    assert_eq!((error.line, error.column, error.found.as_str()), (1, 1, "\"=100\""));
+
+    let error = Day1::parse("R10\ndial 60\n").err().unwrap();
+    assert_eq!(error.line, 2);
+
+    // distances don't have a sign, the direction does that
+    let error = Day1::parse("R-5\n").err().unwrap();
+    assert_eq!((error.column, error.found.as_str()), (2, "\"-5\""));
+    let error = Day1::parse("L-2147483648\n").err().unwrap();
+    assert_eq!((error.column, error.found.as_str()), (2, "\"-2147483648\""));
+}
+
+#[test]
+fn dial_errors_point_at_the_setting_to_blame () {
+    // a bad size is the size setting's fault even when there's a start setting too
+    let error = Day1::parse("start 3\ndial 0\nR1\n").err().unwrap();
+    assert_eq!((error.line, error.found.as_str()), (2, "\"0\""));
+
+    // a start off the dial is the start setting's fault, or the size's if the start is the default
+    let error = Day1::parse("dial 10\nstart 12\nR1\n").err().unwrap();
+    assert_eq!((error.line, error.found.as_str()), (2, "\"12\""));
+    let error = Day1::parse("dial 10\nR1\n").err().unwrap();
+    assert_eq!((error.line, error.found.as_str()), (1, "\"10\""));
+}
+
+#[test]
+fn repeats_are_limited () {
+    let error = Day1::parse("R5\n1000000000x(R1)\n").err().unwrap();
+    assert_eq!((error.line, error.column, error.found.as_str()), (2, 1, "\"1000000000\""));
+
+    let error = Day1::parse("R5\n1000x(1000x(L2) R1)\n").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 1));
+
+    let error = Day1::parse("R5\n99999999999999999999x(R1)\n").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 1));
+
+    assert_eq!(Day1::parse("R5\n0x(R1) 999x(R1)\n").unwrap().steps.len(), 1000);
+
+    // too deep is an error at the first '(' past the limit, not a stack overflow
+    let error = Day1::parse(&format!("{}R1{}\n", "1x(".repeat(5000), ")".repeat(5000))).err().unwrap();
+    assert_eq!((error.line, error.column, error.found.as_str()), (1, 303, "\"(\""));
+    assert_eq!(Day1::parse(&format!("{}R1{}\n", "1x(".repeat(100), ")".repeat(100))).unwrap().steps.len(), 1);
+}
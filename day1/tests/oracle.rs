@@ -0,0 +1,32 @@
+use aoc_common::{random::Rng, Solution};
+use day1::{oracle, Day1, Dial, Step};
+
+// the generated rotations for each seed
+const SEEDS: u64 = 100;
+const ROTATIONS: usize = 1000;
+
+#[test]
+fn rotate_matches_stepping_around_zero () {
+    // every turn up to three times round either way, from everywhere on some awkward dials
+    for size in [1, 2, 3, 7, 60, 100] {
+        for start in 0..size {
+            for delta in (-3 * size)..=(3 * size) {
+                if let Some(divergence) = oracle::first_divergence(Dial::new(size, start).unwrap(), &[Step::Turn(delta)]) {
+                    panic!("dial of size {}: {}", size, divergence);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn rotate_matches_stepping_on_generated_inputs () {
+    for seed in 0..SEEDS {
+        let input = day1::generator::generate(&mut Rng::new(seed), ROTATIONS);
+        let instructions = Day1::parse(&input).unwrap();
+
+        if let Some(divergence) = oracle::first_divergence(instructions.dial, &instructions.steps) {
+            panic!("seed {}, dial of size {}: {}", seed, instructions.dial.size(), divergence);
+        }
+    }
+}
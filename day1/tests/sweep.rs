@@ -0,0 +1,46 @@
+use aoc_common::{random::Rng, Solution};
+use day1::{passwords, sweep::{self, Extremes}, Day1, Passwords};
+
+// the input parsed again from `start`, so the sweep is checked against a dial that really did start there
+fn from_start (input: &str, start: i64) -> Passwords {
+    let body: Vec<&str> = input.lines().filter(|line| !line.starts_with("start ")).collect();
+    let instructions = Day1::parse(&format!("start {}\n{}\n", start, body.join("\n"))).unwrap();
+    return passwords(instructions.dial, &instructions.steps);
+}
+
+#[test]
+fn sweep_matches_starting_from_each_position () {
+    for seed in 0..10 {
+        let input = day1::generator::generate(&mut Rng::new(seed), 100);
+        let instructions = Day1::parse(&input).unwrap();
+        let size = instructions.dial.size();
+
+        let swept = sweep::sweep(size, &instructions.steps);
+        for start in 0..size {
+            assert_eq!(swept[start as usize], from_start(&input, start), "seed {} start {}", seed, start);
+        }
+    }
+}
+
+#[test]
+fn positions_are_the_same_from_every_start () {
+    let instructions = Day1::parse("=0\n").unwrap();
+    let swept = sweep::sweep(instructions.dial.size(), &instructions.steps);
+    assert!(swept.iter().all(|passwords| passwords.first == 1), "{:?}", swept);
+
+    let input = "dial 7\nstart 0\n=3 R4 2x(=6 L9) =0\n";
+    let instructions = Day1::parse(input).unwrap();
+    let swept = sweep::sweep(7, &instructions.steps);
+    for start in 0..7 {
+        assert_eq!(swept[start as usize], from_start(input, start), "start {}", start);
+    }
+}
+
+#[test]
+fn extremes_go_to_the_lowest_start () {
+    let swept = [(1, 5), (3, 2), (3, 7), (0, 7)].map(|(first, second)| Passwords { first: first, second: second });
+
+    assert_eq!(sweep::extremes(&swept, |passwords| passwords.first), Some(Extremes { best: (1, 3), worst: (3, 0) }));
+    assert_eq!(sweep::extremes(&swept, |passwords| passwords.second), Some(Extremes { best: (2, 7), worst: (1, 2) }));
+    assert_eq!(sweep::extremes(&[], |passwords| passwords.first), None);
+}
@@ -0,0 +1,75 @@
+// `size` machines, each with a light diagram and joltages that some set of presses actually reaches
+//
+// both targets are made by pressing the buttons at random rather than picked first, so every
+// machine has an answer
+
+use aoc_common::random::Rng;
+
+use super::{OFF_CHAR, ON_CHAR};
+
+const MIN_LIGHTS: i64 = 4;
+const MAX_LIGHTS: i64 = 10;
+// the real machines have between 2 fewer and 3 more buttons than lights, any more than that and
+// there are too many ways to press them for the joltage solver to search
+const MIN_EXTRA_BUTTONS: i64 = -2;
+const MAX_EXTRA_BUTTONS: i64 = 3;
+const MAX_PRESSES: i64 = 12;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..size.max(1) {
+        lines.push(machine(rng));
+    }
+
+    return lines.join("\n") + "\n";
+}
+
+fn machine (rng: &mut Rng) -> String {
+    let n_lights = rng.range(MIN_LIGHTS, MAX_LIGHTS) as usize;
+    let n_buttons = (n_lights as i64 + rng.range(MIN_EXTRA_BUTTONS, MAX_EXTRA_BUTTONS)) as usize;
+
+    let mut buttons: Vec<Vec<bool>> = (0..n_buttons).map(|_| (0..n_lights).map(|_| rng.chance(0.4)).collect()).collect();
+
+    // every light needs at least one button wired to it
+    for light in 0..n_lights {
+        if !buttons.iter().any(|button| button[light]) {
+            let button_idx = rng.index(n_buttons);
+            buttons[button_idx][light] = true;
+        }
+    }
+    // and every button needs at least one light
+    for button in buttons.iter_mut() {
+        if !button.contains(&true) {
+            button[rng.index(n_lights)] = true;
+        }
+    }
+
+    let mut lights = vec![false; n_lights];
+    let mut joltages = vec![0; n_lights];
+
+    for button in buttons.iter() {
+        // pressing twice is the same as not pressing at all for the lights
+        if rng.chance(0.5) {
+            for light in 0..n_lights {
+                lights[light] ^= button[light];
+            }
+        }
+
+        let presses = rng.range(0, MAX_PRESSES);
+        for light in 0..n_lights {
+            if button[light] {
+                joltages[light] += presses;
+            }
+        }
+    }
+
+    let diagram: String = lights.iter().map(|on| if *on { ON_CHAR } else { OFF_CHAR }).collect();
+    let buttons: Vec<String> = buttons.iter().map(|button| {
+        let wired: Vec<String> = (0..n_lights).filter(|light| button[*light]).map(|light| light.to_string()).collect();
+        return format!("({})", wired.join(","));
+    }).collect();
+    let joltages: Vec<String> = joltages.iter().map(|joltage| joltage.to_string()).collect();
+
+    return format!("[{}] {} {{{}}}", diagram, buttons.join(" "), joltages.join(","));
+}
@@ -1,27 +1,4 @@
-use std::fmt::{Debug, Formatter};
-
-const VERBOSE: bool = false;
-
-
-struct Matrix {
-    n_rows: usize,
-    n_cols: usize,
-    data: Vec<Vec<i32>>
-}
-
-impl Matrix {
-    
-}
-
-impl Debug for Matrix {
-    fn fmt (&self, _: &mut Formatter) -> Result<(), std::fmt::Error> {
-        for row in self.data.iter() {
-            println!("{:?}", row)
-        }
-        
-        return Ok(())
-    }
-}
+use aoc_common::trace;
 
 pub fn reduce_matrix (matrix: &mut Vec<Vec<i32>>, values: &mut Vec<i32>) {
     return do_reduction(matrix, values, 0);
@@ -36,35 +13,31 @@ fn do_reduction (matrix: &mut Vec<Vec<i32>>, values: &mut Vec<i32>, col_idx: usi
         return;
     }
 
-    if VERBOSE {
-        println!("Current matrix:");
-        print_matrix(&matrix);
-        println!("{:?}", values);
-    }
+    trace!("Current matrix:");
+    print_matrix(matrix);
+    trace!("{:?}", values);
 
-    if VERBOSE { println!("targeting column {}", col_idx); }
+    trace!("targeting column {}", col_idx);
 
-    let pivot_rows = find_source_and_target_row(&matrix, col_idx);
+    let pivot_rows = find_source_and_target_row(matrix, col_idx);
     if pivot_rows.is_none() {
         // not possible to pivot on this column
         // try next col
         return do_reduction(matrix, values, col_idx + 1);
     }
     let (source_row_idx, target_row_idx) = pivot_rows.unwrap();
-    if VERBOSE { println!("sourcing from row {}", source_row_idx); }
-    if VERBOSE { println!("targeting row {}", target_row_idx); }
+    trace!("sourcing from row {}", source_row_idx);
+    trace!("targeting row {}", target_row_idx);
 
     let sign = matrix[target_row_idx][col_idx].signum() * matrix[source_row_idx][col_idx].signum();
     if sign == 0 {
         panic!("zero sign")
     }
-    if VERBOSE { println!("sign is {}", sign); }
+    trace!("sign is {}", sign);
 
-    if VERBOSE {
-        println!("Scaling:");
-        println!("before scaling");
-        print_matrix(matrix);
-    }
+    trace!("Scaling:");
+    trace!("before scaling");
+    print_matrix(matrix);
 
     let source_scale_factor = matrix[source_row_idx][col_idx];
     let target_scale_factor =  matrix[target_row_idx][col_idx];
@@ -72,10 +45,8 @@ fn do_reduction (matrix: &mut Vec<Vec<i32>>, values: &mut Vec<i32>, col_idx: usi
     scale_row_by(matrix, values, source_row_idx, target_scale_factor);
     scale_row_by(matrix, values, target_row_idx, source_scale_factor);
 
-    if VERBOSE {
-        println!("after scaling");
-        print_matrix(matrix);
-    }
+    trace!("after scaling");
+    print_matrix(matrix);
 
     // subtract source row from target row
     for col in 0..n_cols {
@@ -89,7 +60,7 @@ fn do_reduction (matrix: &mut Vec<Vec<i32>>, values: &mut Vec<i32>, col_idx: usi
 }
 
 fn scale_row_by(matrix: &mut Vec<Vec<i32>>, values: &mut Vec<i32>, row_idx: usize, scale_factor: i32) {
-    if VERBOSE { println!("Scaling row {} by factor of {}", row_idx, scale_factor) }
+    trace!("Scaling row {} by factor of {}", row_idx, scale_factor);
     let n_cols = matrix[row_idx].len();
     for col_idx in 0..n_cols {
         matrix[row_idx][col_idx] *= scale_factor;
@@ -115,13 +86,12 @@ fn scale_row (matrix: &mut Vec<Vec<i32>>, values: &mut Vec<i32>, row_idx: usize)
     }
 
     for possible_gcd in 2..(min_val + 1) {
-        let mut is_cd = true;
-
         if values[row_idx].abs() % possible_gcd != 0 {
-            is_cd = false;
             continue;
         }
 
+        let mut is_cd = true;
+
         for col_idx in 0..n_cols {
             if matrix[row_idx][col_idx].abs() % possible_gcd != 0 {
                 is_cd = false
@@ -137,7 +107,7 @@ fn scale_row (matrix: &mut Vec<Vec<i32>>, values: &mut Vec<i32>, row_idx: usize)
         return
     }
 
-    if VERBOSE { println!("Scaling row {} by factor of {}", row_idx, gcd); }
+    trace!("Scaling row {} by factor of {}", row_idx, gcd);
 
     for col_idx in 0..n_cols {
         matrix[row_idx][col_idx] /= gcd;
@@ -182,6 +152,6 @@ fn find_source_and_target_row (matrix: &Vec<Vec<i32>>, col_idx: usize) -> Option
 
 pub fn print_matrix (matrix: &Vec<Vec<i32>>) {
     for row in matrix.iter() {
-        println!("{:?}", row);
+        trace!("{:?}", row);
     }
 }
\ No newline at end of file
@@ -0,0 +1,6 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    testing::check_generated::<day10::Day10>(day10::generator::generate, &[1, 5, 10], testing::any_answers);
+}
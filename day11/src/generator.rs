@@ -0,0 +1,120 @@
+// a rack of `size` devices plus the five the puzzle names, wired up without any loops
+//
+// devices are made in reverse, starting from out, and each only connects to ones made before it
+// so the wiring can't loop back on itself. the number of paths from each device to out, dac and
+// fft is tracked as it goes, and a connection that would take any of them over MAX_PATHS is
+// skipped so the counts stay inside the solver's integers
+
+use std::collections::HashSet;
+
+use aoc_common::random::Rng;
+
+use super::{DAC_STRING, END_STRING, FFT_STRING, SERVER_STRING, START_STRING};
+
+const MAX_PATHS: i64 = 1_000_000;
+const MAX_CONNECTIONS: usize = 4;
+
+// the paths from a device to each of these
+const TARGETS: [&str; 3] = [END_STRING, DAC_STRING, FFT_STRING];
+
+struct Device {
+    name: String,
+    connections: Vec<usize>,
+    paths: [i64; 3]
+}
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let n_devices = size + 5;
+
+    // out comes first, dac a third of the way along, fft two thirds and svr last, which makes
+    // everything flow svr -> fft -> dac -> out. you can go anywhere after out
+    let mut names = vec![None; n_devices];
+    names[0] = Some(END_STRING);
+    names[1 + size / 3] = Some(DAC_STRING);
+    names[2 + 2 * size / 3] = Some(FFT_STRING);
+    names[n_devices - 1] = Some(SERVER_STRING);
+
+    let free: Vec<usize> = (1..n_devices).filter(|idx| names[*idx].is_none()).collect();
+    names[*rng.choose(&free)] = Some(START_STRING);
+
+    let mut used_names = HashSet::new();
+    let mut devices: Vec<Device> = vec![];
+
+    for name in names {
+        let name = match name {
+            Some(name) => name.to_string(),
+            None => random_name(rng, &mut used_names),
+        };
+
+        let mut device = Device { name: name, connections: vec![], paths: [0; 3] };
+        if let Some(target_idx) = TARGETS.iter().position(|target| **target == device.name) {
+            device.paths[target_idx] = 1;
+        }
+
+        // out is the end of the line
+        if devices.is_empty() {
+            devices.push(device);
+            continue;
+        }
+
+        // svr has to reach fft, and fft has to reach dac, otherwise part 2 has nothing to count
+        let must_reach = match device.name.as_str() {
+            SERVER_STRING => Some(2),
+            FFT_STRING => Some(1),
+            _ => None,
+        };
+        if let Some(target_idx) = must_reach {
+            let reaching: Vec<usize> = (0..devices.len()).filter(|idx| devices[*idx].paths[target_idx] > 0).collect();
+            connect(&mut device, &devices, *rng.choose(&reaching));
+        }
+
+        let n_connections = rng.range(1, MAX_CONNECTIONS as i64) as usize;
+        // gives up after a while if most of the connections would make too many paths
+        for _ in 0..(4 * MAX_CONNECTIONS) {
+            if device.connections.len() >= n_connections {
+                break;
+            }
+
+            let other = rng.index(devices.len());
+            let paths_after: Vec<i64> = (0..3).map(|target_idx| device.paths[target_idx] + devices[other].paths[target_idx]).collect();
+
+            if !device.connections.contains(&other) && paths_after.iter().all(|paths| *paths <= MAX_PATHS) {
+                connect(&mut device, &devices, other);
+            }
+        }
+
+        // out itself never goes over
+        if device.connections.is_empty() {
+            connect(&mut device, &devices, 0);
+        }
+
+        devices.push(device);
+    }
+
+    // out doesn't connect to anything so it doesn't get a line, and the rest go in any order
+    let mut lines: Vec<String> = devices.iter().skip(1).map(|device| {
+        let connections: Vec<&str> = device.connections.iter().map(|idx| devices[*idx].name.as_str()).collect();
+        return format!("{}: {}", device.name, connections.join(" "));
+    }).collect();
+    rng.shuffle(&mut lines);
+
+    return lines.join("\n") + "\n";
+}
+
+fn connect (device: &mut Device, devices: &[Device], other: usize) {
+    device.connections.push(other);
+    for target_idx in 0..3 {
+        device.paths[target_idx] += devices[other].paths[target_idx];
+    }
+}
+
+// three letters like the real names, without reusing one or taking one the puzzle names
+fn random_name (rng: &mut Rng, used_names: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..3).map(|_| char::from(b'a' + rng.below(26) as u8)).collect();
+
+        if !TARGETS.contains(&name.as_str()) && name != SERVER_STRING && name != START_STRING && used_names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
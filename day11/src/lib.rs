@@ -0,0 +1,135 @@
+use std::collections::{BTreeSet, HashMap};
+
+use aoc_common::{parse::{self, Span}, solution, stats, trace, Answer, ParseError, Solution};
+
+pub mod generator;
+
+const START_STRING: &str = "you";
+const SERVER_STRING: &str = "svr";
+const END_STRING: &str = "out";
+
+const FFT_STRING: &str = "fft";
+const DAC_STRING: &str = "dac";
+
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day11>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day11>(input);
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = HashMap<String, Device>;
+
+    fn parse (input: &str) -> Result<HashMap<String, Device>, ParseError> {
+        let devices = parse_input(input)?;
+        trace!("{:?}", devices);
+
+        return Ok(build_graph(&devices));
+    }
+
+    fn part1 (graph: &HashMap<String, Device>) -> Answer {
+        return find_paths(graph, &START_STRING.to_string(), &END_STRING.to_string()).into();
+    }
+
+    fn part2 (graph: &HashMap<String, Device>) -> Answer {
+        let svr_to_fft = find_paths(graph, &SERVER_STRING.to_string(), &FFT_STRING.to_string());
+        stats::record("svr_to_fft", svr_to_fft);
+
+        let fft_to_dac = find_paths(graph, &FFT_STRING.to_string(), &DAC_STRING.to_string());
+        stats::record("fft_to_dac", fft_to_dac);
+        let dac_to_out = find_paths(graph, &DAC_STRING.to_string(), &END_STRING.to_string());
+        stats::record("dac_to_out", dac_to_out);
+
+        return (svr_to_fft as i64 * fft_to_dac as i64 * dac_to_out as i64).into();
+    }
+}
+
+fn find_paths (graph: &HashMap<String, Device>, start_node: &String, end_node: &String) -> i32 {
+    let visited_nodes: BTreeSet<&String> = BTreeSet::new();
+    // only ever looked up, never iterated, so its order can't leak out
+    let mut memo = HashMap::new();
+
+    return do_find(graph, start_node, end_node, &visited_nodes, &mut memo);
+}
+
+fn do_find (graph: &HashMap<String, Device>, start_node: &String, end_node: &String, visited_nodes: &BTreeSet<&String>, memo: &mut HashMap<(String, String), i32>) -> i32 {
+    let mut n_paths = 0;
+    if start_node == end_node {
+        trace!("reached end");
+        return 1;
+    }
+    let mut new_visited_nodes = visited_nodes.clone();
+    new_visited_nodes.insert(start_node);
+    trace!("visiting node [{}]", start_node);
+    trace!("already visited: {:?}", visited_nodes);
+
+    if !graph.contains_key(start_node) {
+        trace!("dead end: no node with name {}", start_node);
+        return 0;
+    }
+
+    let start_device = graph.get(start_node).unwrap();
+    for connection in start_device.connected_devices.iter() {
+        if !new_visited_nodes.contains(connection) {
+            let next_n_paths;
+            let key = (connection.clone(), end_node.clone());
+            if memo.contains_key(&key) {
+                next_n_paths = *memo.get(&key).unwrap()
+            } else {
+                next_n_paths = do_find(graph, connection, end_node, &new_visited_nodes, memo);
+            }
+            // let 
+            n_paths += next_n_paths;
+            memo.insert(key, next_n_paths);
+        }
+    }
+
+    return n_paths;
+}
+
+fn parse_input (input: &str) -> Result<Vec<Device>, ParseError> {
+    return parse::each_line(input, Device::from_line);
+}
+
+fn build_graph (devices: &Vec<Device>) -> HashMap<String, Device> {
+    let mut graph = HashMap::new();
+
+    for device in devices {
+        graph.insert(device.name.clone(), device.clone());
+    }
+
+    return graph;
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct Device {
+    name: String,
+    // in name order, so devices are always visited and traced in the same order
+    connected_devices: BTreeSet<String>
+}
+
+impl Device {
+    fn from_line (line: Span) -> Result<Device, ParseError> {
+        let (name, outputs) = line.split_once(": ", "\": \" followed by the connected devices")?;
+
+        if name.is_empty() || name.text.contains(char::is_whitespace) {
+            return Err(name.error("a device name"));
+        }
+
+        let connections = outputs.words();
+        if connections.is_empty() {
+            return Err(line.error_after("at least one connected device"));
+        }
+
+        return Ok(Device {
+            name: name.text.to_string(),
+            connected_devices: connections.iter().map(|connection| connection.text.to_string()).collect() })
+    }
+}
\ No newline at end of file
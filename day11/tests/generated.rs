@@ -0,0 +1,6 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    testing::check_generated::<day11::Day11>(day11::generator::generate, &[0, 10, 100], testing::any_answers);
+}
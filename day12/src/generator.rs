@@ -0,0 +1,94 @@
+// the same six kinds of 3x3 piece as the real input, then `size` regions to pack
+//
+// the real regions are either clearly roomy enough or clearly too small, so most of these are
+// too, with the occasional one in between to exercise the indeterminate case
+
+use aoc_common::{grid::Grid, random::Rng};
+
+use super::{EMPTY_CHAR, SHAPE_PART};
+
+const N_SHAPES: usize = 6;
+const SHAPE_SIZE: usize = 3;
+const MIN_PARTS: usize = 5;
+const MAX_PARTS: usize = 7;
+const MIN_SIDE: i64 = 35;
+const MAX_SIDE: i64 = 50;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+    let mut shape_sizes = vec![];
+
+    for id in 0..N_SHAPES {
+        let shape = shape(rng);
+        shape_sizes.push(shape.iter().filter(|(_, part)| **part).count());
+
+        lines.push(format!("{}:", id));
+        for row in shape.rows() {
+            lines.push(row.iter().map(|part| if *part { SHAPE_PART } else { EMPTY_CHAR }).collect());
+        }
+        lines.push(String::new());
+    }
+
+    for _ in 0..size.max(1) {
+        let width = rng.range(MIN_SIDE, MAX_SIDE) as usize;
+        let height = rng.range(MIN_SIDE, MAX_SIDE) as usize;
+        let area = width * height;
+        let area_in_boxes = area / (SHAPE_SIZE * SHAPE_SIZE);
+        let smallest_shape = *shape_sizes.iter().min().unwrap();
+
+        let n_pieces = match rng.below(10) {
+            // every piece fits in its own 3x3 box
+            0..=4 => rng.range(area_in_boxes as i64 * 3 / 4, area_in_boxes as i64) as usize,
+            // more parts than the region has room for, however they're packed
+            5..=8 => area / smallest_shape + rng.range(1, 20) as usize,
+            _ => area_in_boxes + rng.range(1, 10) as usize,
+        };
+
+        let mut counts = [0; N_SHAPES];
+        for _ in 0..n_pieces {
+            counts[rng.index(N_SHAPES)] += 1;
+        }
+
+        let counts: Vec<String> = counts.iter().map(|count| count.to_string()).collect();
+        lines.push(format!("{}x{}: {}", width, height, counts.join(" ")));
+    }
+
+    return lines.join("\n") + "\n";
+}
+
+// a piece that fills its 3x3 box edge to edge, in one connected lump
+fn shape (rng: &mut Rng) -> Grid<bool> {
+    loop {
+        let n_parts = rng.range(MIN_PARTS as i64, MAX_PARTS as i64) as usize;
+
+        let mut cells: Vec<usize> = (0..(SHAPE_SIZE * SHAPE_SIZE)).collect();
+        rng.shuffle(&mut cells);
+        let parts = &cells[..n_parts];
+
+        let shape = Grid::from_fn(SHAPE_SIZE, SHAPE_SIZE, |x, y| parts.contains(&(y * SHAPE_SIZE + x)));
+
+        let fills_rows = shape.rows().all(|row| row.contains(&true));
+        let fills_columns = shape.columns().all(|mut column| column.any(|part| *part));
+
+        if fills_rows && fills_columns && is_connected(&shape, n_parts) {
+            return shape;
+        }
+    }
+}
+
+fn is_connected (shape: &Grid<bool>, n_parts: usize) -> bool {
+    let start = shape.coords().find(|coords| shape[*coords]).unwrap();
+    let mut seen = vec![start];
+    let mut to_visit = vec![start];
+
+    while let Some((x, y)) = to_visit.pop() {
+        for neighbour in shape.neighbours4(x, y) {
+            if shape[neighbour] && !seen.contains(&neighbour) {
+                seen.push(neighbour);
+                to_visit.push(neighbour);
+            }
+        }
+    }
+
+    return seen.len() == n_parts;
+}
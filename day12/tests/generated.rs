@@ -0,0 +1,6 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    testing::check_generated::<day12::Day12>(day12::generator::generate, &[1, 10, 50], testing::any_answers);
+}
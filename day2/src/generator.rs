@@ -0,0 +1,26 @@
+// `size` ID ranges, on one comma separated line like the real input
+
+use aoc_common::random::Rng;
+
+const MAX_DIGITS: u32 = 10;
+// every ID in every range gets checked, so the ranges can't get too wide
+const MAX_WIDTH: i64 = 100_000;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let mut ranges: Vec<(i64, i64)> = vec![];
+
+    while ranges.len() < size.max(1) {
+        let n_digits = rng.range(1, MAX_DIGITS as i64) as u32;
+        let start = rng.range(10_i64.pow(n_digits - 1), 10_i64.pow(n_digits) - 1);
+        // allowed to run into the next number of digits, like 95-115 does
+        let end = start + rng.range(0, MAX_WIDTH.min(start));
+
+        // the real ranges never overlap, so nothing gets counted twice
+        if ranges.iter().all(|(other_start, other_end)| end < *other_start || *other_end < start) {
+            ranges.push((start, end));
+        }
+    }
+
+    let ranges: Vec<String> = ranges.iter().map(|(start, end)| format!("{}-{}", start, end)).collect();
+    return ranges.join(",");
+}
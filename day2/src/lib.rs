@@ -0,0 +1,99 @@
+use aoc_common::{parse, solution, Answer, ParseError, Solution};
+
+pub mod generator;
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day2>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day2>(input);
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    type Input = Vec<(i64, i64)>;
+
+    fn parse (input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1 (ranges: &Vec<(i64, i64)>) -> Answer {
+        return sum_matching(ranges, is_reduplicated).into();
+    }
+
+    fn part2 (ranges: &Vec<(i64, i64)>) -> Answer {
+        return sum_matching(ranges, is_repeated).into();
+    }
+}
+
+fn sum_matching (ranges: &Vec<(i64, i64)>, matches: fn(i64) -> bool) -> i64 {
+    let mut total = 0;
+
+    for range in ranges {
+        let start = range.0;
+        let end = range.1;
+
+        for num in start..(end + 1) {
+            if matches(num) {
+                total += num;
+            }
+        }
+    }
+
+    return total
+}
+
+fn is_repeated (num: i64) -> bool {
+    let num_string = num.to_string();
+    let num_len = num_string.len();
+
+    for i in 2..(num_len + 1) {
+        if num_len.is_multiple_of(i) && is_repeated_n_times(num, i) {
+            return true
+        }
+    }
+
+    return false
+}
+
+fn is_repeated_n_times(num: i64, n: usize) -> bool {
+    let num_string = num.to_string();
+    let num_len = num_string.len();
+
+    let step_size = num_len/n;
+    
+    let mut s1 = &num_string[..step_size];
+
+    for i in 1..n {
+        let s2 = &num_string[(step_size * i)..(step_size * (i + 1))];
+
+        if s1 != s2 {
+            return false
+        }
+        s1 = s2;
+    }
+
+    return true
+}
+
+fn is_reduplicated (num: i64) -> bool {
+    let num_string = num.to_string();
+    let num_len = num_string.len();
+
+    if num_len % 2 == 1 {
+        return false;
+    }
+
+    let first_half = &num_string[..num_len/2];
+    let second_half = &num_string[num_len/2..];
+
+    return first_half == second_half;
+}
+
+fn parse_input (input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let lines = parse::each_line(input, |line| line.separated(",", |range| range.range::<i64>("a range like 11-22")))?;
+    return Ok(lines.concat());
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    // an ID made of a sequence repeated twice is also made of a sequence repeated at least twice
+    testing::check_generated::<day2::Day2>(day2::generator::generate, &[1, 5, 10], |part1, part2| part2.as_number() >= part1.as_number());
+}
@@ -0,0 +1,16 @@
+// `size` banks of batteries, each as long as the ones in the real input
+
+use aoc_common::random::Rng;
+
+const BANK_LENGTH: usize = 100;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..size.max(1) {
+        let bank: String = (0..BANK_LENGTH).map(|_| char::from(b'0' + rng.range(1, 9) as u8)).collect();
+        lines.push(bank);
+    }
+
+    return lines.join("\n") + "\n";
+}
@@ -0,0 +1,145 @@
+use aoc_common::{debug, parse, solution, trace, Answer, ParseError, Solution};
+
+pub mod generator;
+
+// batteries to turn on in each bank for part 2
+const N_BATTERIES: u64 = 12;
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day3>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day3>(input);
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    type Input = Vec<Vec<u64>>;
+
+    fn parse (input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1 (banks: &Vec<Vec<u64>>) -> Answer {
+        let mut total = 0;
+
+        for (bank_idx, bank) in banks.iter().enumerate() {
+            let mut first_digit = 0;
+            let mut first_digit_index = 0;
+
+            for (i, value) in bank[..(bank.len()-1)].iter().enumerate() {
+                if *value > first_digit {
+                    first_digit = *value;
+                    first_digit_index = i;
+                }
+            }
+
+            let second_digit = bank[(first_digit_index+1)..].iter().max().unwrap();
+
+            trace!("Bank {} | value 1 {}", bank_idx,  first_digit * 10 + second_digit);
+            total += first_digit * 10 + second_digit;
+        }
+
+        return total.into();
+    }
+
+    fn part2 (banks: &Vec<Vec<u64>>) -> Answer {
+        let mut total_2 = 0;
+        let mut total_2_1 = 0;
+
+        for (bank_idx, bank) in banks.iter().enumerate() {
+            let joltage_2 = turn_on_n_batteries(bank, N_BATTERIES);
+            let joltage_2_1 = vec_to_num(&n_batteries(bank.as_slice(), N_BATTERIES));
+
+            trace!("Bank {} | value 2 {}", bank_idx,  joltage_2);
+            trace!("Bank {} | value 2 {} (recursive way)", bank_idx,  joltage_2_1);
+
+            total_2 += joltage_2;
+            total_2_1 += joltage_2_1;
+        }
+
+        debug!("Total 2 is {} (recursive way)", total_2_1);
+        return total_2.into();
+    }
+}
+
+fn n_batteries (bank: &[u64], n: u64) -> Vec<u64> {
+    if n == 0 {
+        return vec![];
+    }
+    let mut first_digit = 0;
+    let mut first_digit_index = 0;
+
+    for (i, value) in bank[..(bank.len() - n as usize + 1)].iter().enumerate() {
+        if *value > first_digit {
+            first_digit = *value;
+            first_digit_index = i;
+        }
+    }
+
+    let mut result = vec![first_digit];
+    result.append(&mut n_batteries(&bank[(first_digit_index + 1)..], n - 1));
+
+    return result
+}
+
+fn turn_on_n_batteries (bank: &Vec<u64>, n: u64) -> u64 {
+    if bank.len() as u64 <= n {
+        trace!("{:?}", bank);
+        return vec_to_num(bank);
+    }
+
+    let mut digits = vec![];
+    let mut digit_indices = vec![];
+
+    for digit_idx in 0..(n-1) {
+        let mut max = 0;
+        let mut max_idx = 0;
+
+        let start_idx = if digit_idx == 0 { 0 } else { digit_indices[digit_idx as usize - 1] + 1 };
+
+        for (i, value) in bank[start_idx..(bank.len() - n as usize + digit_idx as usize + 1)].iter().enumerate() {
+            if *value > max {
+                max = *value;
+                max_idx = i;
+            }
+        }
+        digits.push(max);
+        digit_indices.push(max_idx + start_idx);
+    }
+
+    trace!("{:?}", digits);
+
+    let last_digit = bank[(digit_indices[n as usize - 2]+1)..].iter().max().unwrap();
+    digits.push(*last_digit);
+
+    return vec_to_num(&digits);
+}
+
+fn vec_to_num (bank: &Vec<u64>) -> u64 {
+    let mut total = 0;
+    let bank_len = bank.len();
+
+    for (i, value) in bank.iter().enumerate() {
+        // total += ((bank_len - i - 1) as u64).pow(10) * value;
+        total += 10_u64.pow((bank_len - i - 1) as u32) * value;
+    }
+    
+    return total
+}
+
+fn parse_input (input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    return parse::each_line(input, |line| {
+        let bank = line.chars().into_iter()
+            .map(|(battery, c)| c.to_digit(10).map(u64::from).ok_or(battery.error("a battery joltage digit")))
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+
+        if bank.len() < N_BATTERIES as usize {
+            return Err(line.error_after(format!("at least {} batteries", N_BATTERIES)));
+        }
+        return Ok(bank);
+    });
+}
\ No newline at end of file
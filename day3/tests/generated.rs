@@ -0,0 +1,7 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    // twelve batteries always beat two
+    testing::check_generated::<day3::Day3>(day3::generator::generate, &[1, 10, 50], |part1, part2| part2.as_number() > part1.as_number());
+}
@@ -0,0 +1,20 @@
+// a `size` by `size` grid of rolls of paper
+
+use aoc_common::random::Rng;
+
+use super::{PAPER_CHAR, SPACE_CHAR};
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    // how tightly packed the rolls are changes how many rounds of removal it takes
+    let density = rng.range(50, 80) as f64 / 100.0;
+
+    let mut lines = vec![];
+
+    for _ in 0..size {
+        let row: String = (0..size).map(|_| if rng.chance(density) { PAPER_CHAR } else { SPACE_CHAR }).collect();
+        lines.push(row);
+    }
+
+    return lines.join("\n") + "\n";
+}
@@ -0,0 +1,70 @@
+use aoc_common::{grid::Grid, solution, Answer, ParseError, Solution};
+
+pub mod generator;
+
+const PAPER_CHAR: char = '@';
+const SPACE_CHAR: char = '.';
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day4>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day4>(input);
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = Grid<char>;
+
+    fn parse (input: &str) -> Result<Grid<char>, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1 (grid: &Grid<char>) -> Answer {
+        let mut total = 0;
+
+        for (x, y) in grid.coords() {
+            if grid[(x, y)] == PAPER_CHAR && count_adjacent(grid, x, y) < 4 {
+                total += 1;
+            }
+        }
+
+        return total.into();
+    }
+
+    fn part2 (grid: &Grid<char>) -> Answer {
+        return remove_rolls(grid.clone()).into();
+    }
+}
+
+fn remove_rolls (mut grid: Grid<char>) -> usize {
+    let mut total_removed = 0;
+
+    loop {
+        let mut removed = 0;
+
+        for (x, y) in grid.coords() {
+            if grid[(x, y)] == PAPER_CHAR && count_adjacent(&grid, x, y) < 4 {
+                grid[(x, y)] = SPACE_CHAR;
+                removed += 1;
+                total_removed += 1;
+            }
+        }
+
+        if removed == 0 {
+            break;
+        }
+    }
+    return total_removed;
+}
+
+fn count_adjacent (grid: &Grid<char>, x: usize, y: usize) -> usize {
+    return grid.neighbours8(x, y).filter(|coords| grid[*coords] == PAPER_CHAR).count();
+}
+
+fn parse_input (input: &str) -> Result<Grid<char>, ParseError> {
+    return Grid::parse(input, |c| if c == PAPER_CHAR || c == SPACE_CHAR { Some(c) } else { None }, &format!("{:?} or {:?}", PAPER_CHAR, SPACE_CHAR));
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    // the first round of removal is what part 1 counts
+    testing::check_generated::<day4::Day4>(day4::generator::generate, &[1, 5, 20], |part1, part2| part2.as_number() >= part1.as_number());
+}
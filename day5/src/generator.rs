@@ -0,0 +1,42 @@
+// `size` fresh ID ranges followed by five times as many ingredient IDs to check
+
+use aoc_common::random::Rng;
+
+// the same scale as the real IDs
+const MAX_ID: i64 = 500_000_000_000_000;
+const MAX_WIDTH: i64 = 2_000_000_000_000;
+const INGREDIENTS_PER_RANGE: usize = 5;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let mut ranges: Vec<(i64, i64)> = vec![];
+
+    for _ in 0..size.max(1) {
+        // plenty of the ranges overlap an earlier one, which is what merging them has to handle
+        let start = if ranges.is_empty() || rng.chance(0.5) {
+            rng.range(1, MAX_ID)
+        } else {
+            let (other_start, other_end) = *rng.choose(&ranges);
+            rng.range(other_start, other_end)
+        };
+        // a few cover a single ID
+        let width = if rng.chance(0.1) { 0 } else { rng.range(1, MAX_WIDTH) };
+
+        ranges.push((start, start + width));
+    }
+
+    let mut lines: Vec<String> = ranges.iter().map(|(start, end)| format!("{}-{}", start, end)).collect();
+    lines.push(String::new());
+
+    for _ in 0..(size.max(1) * INGREDIENTS_PER_RANGE) {
+        // about half land in a range, the rest are anywhere and mostly spoiled
+        let ingredient = if rng.chance(0.5) {
+            let (start, end) = *rng.choose(&ranges);
+            rng.range(start, end)
+        } else {
+            rng.range(1, MAX_ID + MAX_WIDTH)
+        };
+        lines.push(ingredient.to_string());
+    }
+
+    return lines.join("\n") + "\n";
+}
@@ -0,0 +1,135 @@
+use std::{collections::BTreeSet, cmp::{min, max}};
+
+use aoc_common::{parse, solution, stats, Answer, ParseError, Solution};
+
+pub mod generator;
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day5>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day5>(input);
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    type Input = (Vec<(i64, i64)>, Vec<i64>);
+
+    fn parse (input: &str) -> Result<(Vec<(i64, i64)>, Vec<i64>), ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1 ((ranges, ingredients): &(Vec<(i64, i64)>, Vec<i64>)) -> Answer {
+        let range_set = build_range_set(ranges);
+        let mut fresh_count = 0;
+
+        for ingredient in ingredients {
+            if range_set.contains(*ingredient) {
+                fresh_count += 1;
+            }
+        }
+
+        return fresh_count.into();
+    }
+
+    fn part2 ((ranges, _ingredients): &(Vec<(i64, i64)>, Vec<i64>)) -> Answer {
+        let range_set = build_range_set(ranges);
+        stats::record("ranges", ranges.len());
+        stats::record("condensed_ranges", range_set.len());
+
+        return range_set.size().into();
+    }
+}
+
+fn build_range_set (ranges: &Vec<(i64, i64)>) -> RangeSet {
+    let mut range_set = RangeSet::new();
+
+    for range in ranges.iter() {
+        range_set.insert(*range);
+    }
+
+    return range_set;
+}
+
+fn parse_input (input: &str) -> Result<(Vec<(i64, i64)>, Vec<i64>), ParseError> {
+    // the ranges, then every ingredient after the blank line
+    let sections = parse::sections(input);
+    let (range_lines, ingredient_lines) = match sections.split_first() {
+        Some((range_lines, ingredient_lines)) => (range_lines.clone(), ingredient_lines.concat()),
+        None => (vec![], vec![]),
+    };
+
+    let ranges = range_lines.iter().map(|line| line.range::<i64>("a range like 3-5")).collect::<Result<Vec<(i64, i64)>, ParseError>>()?;
+    let ingredients = ingredient_lines.iter().map(|line| line.parse::<i64>("an ingredient id")).collect::<Result<Vec<i64>, ParseError>>()?;
+
+    return Ok((ranges, ingredients));
+}
+
+fn is_within (value: i64, range: (i64, i64)) -> bool {
+    return range.0 <= value && value <= range.1;
+}
+
+fn intersects (range_1: (i64, i64), range_2: (i64, i64)) -> bool {
+    return is_within(range_1.0, range_2) || is_within(range_1.1, range_2) || is_within(range_2.0, range_1) || is_within(range_2.1, range_1)
+}
+
+fn overlap (range_1: (i64, i64), range_2: (i64, i64)) -> Option<(i64, i64)> {
+    if !intersects(range_1, range_2) {
+        return None
+    }
+
+    return Some((min(range_1.0, range_2.0), max(range_1.1, range_2.1)))
+}
+
+// kept in order of where each range starts, so going through them always goes the same way
+struct RangeSet {
+    ranges: BTreeSet<(i64, i64)>
+}
+
+impl RangeSet {
+    fn new () -> RangeSet {
+        return RangeSet { ranges: BTreeSet::new() }
+    }
+
+    fn contains (&self, value: i64) -> bool {
+        for range in self.ranges.iter() {
+            if range.0 <= value && value <= range.1 {
+                return true
+            }
+        }
+        return false
+    }
+
+    fn size (&self) -> i64 {
+        let mut size = 0;
+        for range in self.ranges.iter() {
+            size += range.1 - range.0 + 1;
+        }
+        return size
+    }
+
+    fn insert (&mut self, candidate_range: (i64, i64)) {
+        let mut possible_range = candidate_range;
+        let mut deletions = vec![];
+
+        for existing_range in self.ranges.iter() {
+            let union = overlap(possible_range, *existing_range);
+            if union.is_some() {
+                deletions.push(*existing_range);
+                possible_range = union.unwrap();
+            }
+        }
+
+        for del in deletions {
+            self.ranges.remove(&del);
+        }
+        self.ranges.insert(possible_range);
+    }
+
+    fn len (&self) -> usize {
+        return self.ranges.len();
+    }
+}
\ No newline at end of file
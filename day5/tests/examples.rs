@@ -0,0 +1,4 @@
+#[test]
+fn examples () {
+    aoc_common::testing::check_examples::<day5::Day5>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,52 @@
+// a worksheet of `size` problems, laid out in columns the same way as the real one
+
+use aoc_common::random::Rng;
+
+use super::{ADDITION, MULTIPLICATION, WHITESPACE};
+
+const N_OPERAND_ROWS: usize = 4;
+// a product of four 3 digit numbers stays well inside an i64, even summed over a big worksheet
+const MAX_DIGITS: i64 = 3;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let mut rows = vec![String::new(); N_OPERAND_ROWS + 1];
+
+    for problem_idx in 0..size.max(1) {
+        // problems are separated by a single blank column
+        if problem_idx > 0 {
+            for row in rows.iter_mut() {
+                row.push(WHITESPACE);
+            }
+        }
+
+        let width = rng.range(1, MAX_DIGITS) as usize;
+        let mut operands = vec![];
+        for _ in 0..N_OPERAND_ROWS {
+            let n_digits = rng.range(1, width as i64) as u32;
+            operands.push(number(rng, n_digits));
+        }
+        // the widest number decides how wide the problem is, so there has to be one that wide
+        let widest = rng.index(N_OPERAND_ROWS);
+        operands[widest] = number(rng, width as u32);
+
+        // each problem lines its numbers up on one side or the other
+        let align_left = rng.chance(0.5);
+        for (row, operand) in rows.iter_mut().zip(operands.iter()) {
+            if align_left {
+                row.push_str(&format!("{:<width$}", operand, width = width));
+            } else {
+                row.push_str(&format!("{:>width$}", operand, width = width));
+            }
+        }
+
+        let operator = if rng.chance(0.5) { ADDITION } else { MULTIPLICATION };
+        rows[N_OPERAND_ROWS].push_str(&format!("{:<width$}", operator, width = width));
+    }
+
+    return rows.join("\n") + "\n";
+}
+
+// a random number with exactly this many digits
+fn number (rng: &mut Rng, n_digits: u32) -> String {
+    return rng.range(10_i64.pow(n_digits - 1), 10_i64.pow(n_digits) - 1).to_string();
+}
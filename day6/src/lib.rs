@@ -0,0 +1,232 @@
+use aoc_common::{grid::Grid, parse, solution, trace, Answer, ParseError, Solution};
+
+pub mod generator;
+
+const ADDITION: char = '+';
+const MULTIPLICATION: char = '*';
+const WHITESPACE: char = ' ';
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day6>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day6>(input);
+}
+
+pub struct Day6;
+
+pub struct Worksheet {
+    problems: Vec<Problem>,
+    grid: Grid<char>
+}
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    type Input = Worksheet;
+
+    fn parse (input: &str) -> Result<Worksheet, ParseError> {
+        return Ok(Worksheet { problems: parse_input(input)?, grid: parse_input_grid(input)? });
+    }
+
+    fn part1 (worksheet: &Worksheet) -> Answer {
+        let mut total = 0;
+
+        for problem in worksheet.problems.iter() {
+            let ans = solve(problem);
+            total += ans.unwrap();
+        }
+
+        return total.into();
+    }
+
+    fn part2 (worksheet: &Worksheet) -> Answer {
+        let part_2_problems = grid_to_problems(&worksheet.grid);
+
+        let mut total_2 = 0;
+        for problem in part_2_problems.iter() {
+            let ans = solve(problem);
+            total_2 += ans.unwrap();
+            trace!("{}", ans.unwrap());
+        }
+
+        return total_2.into();
+    }
+}
+
+fn solve (problem: &Problem) -> Option<i64> {
+    if problem.operator == ADDITION {
+        let mut total = 0;
+        for op in problem.operands.iter() {
+            total += op;
+        }
+        return Some(total);
+    } else if problem.operator == MULTIPLICATION {
+        let mut total = 1;
+        for op in problem.operands.iter() {
+            total *= op;
+        }
+        return Some(total);
+    }
+
+    return None
+}
+
+// the operator row is the last one, everything above it is digits
+fn parse_input_grid (input: &str) -> Result<Grid<char>, ParseError> {
+    let n_lines = input.lines().count();
+
+    let grid = Grid::parse_with(input, |(_x, y), c| {
+        if y == n_lines - 1 {
+            if c == ADDITION || c == MULTIPLICATION || c == WHITESPACE {
+                return Ok(c);
+            }
+            return Err("an operator".to_string());
+        }
+
+        if c.is_ascii_digit() || c == WHITESPACE {
+            return Ok(c);
+        }
+        return Err("a digit".to_string());
+    })?;
+
+    if grid.height() < 2 {
+        return Err(ParseError::end_of_input(input, "rows of numbers followed by a row of operators"));
+    }
+    check_columns(input, &grid)?;
+    return Ok(grid)
+}
+
+// each problem is a run of columns with one operator under it and at least one digit in every
+// column, and problems are separated by a single blank column
+fn check_columns (input: &str, grid: &Grid<char>) -> Result<(), ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let operator_row = grid.height() - 1;
+    let error = |x: usize, y: usize, expected: &str| {
+        return ParseError::at_column(y + 1, lines[y], x + 1, expected, format!("{:?}", grid[(x, y)]));
+    };
+
+    // where the problem being checked started, and whether it has its operator yet
+    let mut problem_start = 0;
+    let mut has_operator = false;
+
+    for x in 0..=grid.width() {
+        let blank = x == grid.width() || grid.column(x).all(|c| *c == WHITESPACE);
+
+        if blank {
+            if x == problem_start {
+                return Err(error(x.min(grid.width() - 1), 0, "a digit"));
+            }
+            if !has_operator {
+                return Err(error(problem_start, operator_row, "an operator"));
+            }
+            problem_start = x + 1;
+            has_operator = false;
+            continue;
+        }
+
+        if grid.column(x).take(operator_row).all(|c| *c == WHITESPACE) {
+            return Err(error(x, 0, "a digit"));
+        }
+        if grid[(x, operator_row)] != WHITESPACE {
+            if has_operator {
+                return Err(error(x, operator_row, "only one operator for each problem"));
+            }
+            has_operator = true;
+        }
+    }
+
+    return Ok(());
+}
+
+// reading the columns right to left or left to right gives the same total,
+// so go left to right and start a new problem at every blank column
+fn grid_to_problems (grid: &Grid<char>) -> Vec<Problem> {
+    let mut problems = vec![];
+
+    let mut problem = Problem::new(); 
+    for column in grid.columns() {
+        let column: Vec<char> = column.cloned().collect();
+        let (operator, digits) = column.split_last().unwrap();
+
+        if column.iter().all(|c| *c == WHITESPACE) {
+            problems.push(problem);
+            problem = Problem::new();
+            continue;
+        }
+
+        if *operator == MULTIPLICATION || *operator == ADDITION {
+            problem.operator = *operator;
+        }
+
+        problem.operands.push(parse_digits(digits));
+    }
+    problems.push(problem);
+
+    return problems
+}
+
+fn parse_digits (digits: &[char]) -> i64 {
+    let mut total = 0;
+    for d in digits.iter().filter(|d| **d != WHITESPACE) {
+        total = total * 10 + d.to_digit(10).unwrap() as i64;
+    }
+    return total
+}
+
+fn parse_input (input: &str) -> Result<Vec<Problem>, ParseError> {
+    let mut input_mode = "operands";
+
+    let mut problems: Vec<Problem> = vec![];
+
+    for (idx, line) in parse::lines(input).into_iter().enumerate() {
+        if line.text.starts_with(ADDITION) || line.text.starts_with(MULTIPLICATION) {
+            input_mode = "operators";
+        }
+
+        if input_mode == "operands" {
+            for (op_idx, token) in line.words().into_iter().enumerate() {
+                let operand = token.parse::<i64>("a number")?;
+
+                if idx == 0 {
+                    problems.push(Problem {
+                        operands: vec![operand],
+                        operator: '-',
+                    });
+                } else if op_idx < problems.len() {
+                    problems[op_idx].operands.push(operand);
+                } else {
+                    return Err(token.error(format!("only {} numbers", problems.len())));
+                }
+            }
+            
+        } else if input_mode == "operators" {
+            for (op_idx, token) in line.words().into_iter().enumerate() {
+                if token.text != ADDITION.to_string() && token.text != MULTIPLICATION.to_string() {
+                    return Err(token.error(format!("{:?} or {:?}", ADDITION, MULTIPLICATION)));
+                }
+                if op_idx >= problems.len() {
+                    return Err(token.error(format!("only {} operators", problems.len())));
+                }
+                problems[op_idx].operator = token.text.chars().next().unwrap();
+            }
+        }
+    }
+
+    if problems.is_empty() || problems.iter().any(|problem| problem.operator != ADDITION && problem.operator != MULTIPLICATION) {
+        return Err(ParseError::end_of_input(input, "an operator for every problem"));
+    }
+
+    return Ok(problems);
+}
+
+struct Problem {
+    operands: Vec<i64>,
+    operator: char
+}
+
+impl Problem {
+    fn new () -> Problem {
+        return Problem { operands: vec![], operator: '_' }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use aoc_common::Solution;
+use day6::Day6;
+
+#[test]
+fn column_groups_are_checked_when_parsing () {
+    // two blank columns in a row would make an empty problem
+    let error = Day6::parse("1  2\n+  *\n").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 3));
+
+    // an operator with nothing above it
+    let error = Day6::parse("1 2\n+ *+\n").err().unwrap();
+    assert_eq!(error.line, 2);
+
+    let worksheet = Day6::parse("12 3\n+  *\n").unwrap();
+    assert_eq!((Day6::part1(&worksheet).to_string(), Day6::part2(&worksheet).to_string()), ("15".to_string(), "6".to_string()));
+}
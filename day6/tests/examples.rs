@@ -0,0 +1,4 @@
+#[test]
+fn examples () {
+    aoc_common::testing::check_examples::<day6::Day6>(env!("CARGO_MANIFEST_DIR"));
+}
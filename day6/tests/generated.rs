@@ -0,0 +1,6 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    testing::check_generated::<day6::Day6>(day6::generator::generate, &[1, 10, 50], testing::any_answers);
+}
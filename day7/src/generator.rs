@@ -0,0 +1,39 @@
+// a manifold with `size` rows of splitters below the start, fanning out the same way as the
+// real input so no beam ever leaves the sides
+
+use aoc_common::random::Rng;
+
+use super::{EMPTY_CHAR, SPLITTER_CHAR, START_CHAR};
+
+// how often a spot in the fan actually has a splitter
+const SPLITTER_CHANCE: f64 = 0.8;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let width = 2 * size + 3;
+    let start_x = size + 1;
+
+    let mut lines = vec![];
+
+    let mut start_line = vec![EMPTY_CHAR; width];
+    start_line[start_x] = START_CHAR;
+    lines.push(start_line.iter().collect::<String>());
+
+    // the nth row of splitters spans n spots either side of the start, every other column
+    for splitter_row in 0..size {
+        lines.push(EMPTY_CHAR.to_string().repeat(width));
+
+        let mut line = vec![EMPTY_CHAR; width];
+        for x in ((start_x - splitter_row)..=(start_x + splitter_row)).step_by(2) {
+            // the first splitter is always there, otherwise the beam goes straight through
+            if splitter_row == 0 || rng.chance(SPLITTER_CHANCE) {
+                line[x] = SPLITTER_CHAR;
+            }
+        }
+        lines.push(line.iter().collect::<String>());
+    }
+
+    lines.push(EMPTY_CHAR.to_string().repeat(width));
+
+    return lines.join("\n") + "\n";
+}
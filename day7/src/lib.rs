@@ -0,0 +1,109 @@
+use std::{collections::HashSet, collections::HashMap};
+
+use aoc_common::{geometry::{Direction, Point2}, grid::Grid, solution, trace, Answer, ParseError, Solution};
+
+pub mod generator;
+
+const START_CHAR: char = 'S';
+const SPLITTER_CHAR: char = '^';
+const EMPTY_CHAR: char = '.';
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day7>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day7>(input);
+}
+
+pub struct Day7;
+
+pub struct Manifold {
+    grid: Grid<char>,
+    start_coords: Point2
+}
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    type Input = Manifold;
+
+    fn parse (input: &str) -> Result<Manifold, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1 (manifold: &Manifold) -> Answer {
+        let (hit_splitters, _tachyon_beams) = fire_beams(manifold);
+        return hit_splitters.len().into();
+    }
+
+    fn part2 (manifold: &Manifold) -> Answer {
+        let (_hit_splitters, tachyon_beams) = fire_beams(manifold);
+
+        let mut total = 0;
+        for (_beam, count) in tachyon_beams {
+            total += count;
+        }
+        return total.into();
+    }
+}
+
+// runs the beams down the manifold one layer at a time
+// returns every splitter that was hit, and how many timelines end up at each beam position
+fn fire_beams (manifold: &Manifold) -> (HashSet<Point2>, HashMap<Point2, i64>) {
+    let grid = &manifold.grid;
+
+    let mut tachyon_beams = HashMap::new();
+    tachyon_beams.insert(manifold.start_coords, 1_i64);
+
+    let mut hit_splitters = HashSet::new();
+
+    let mut layer = 0;
+    while layer < grid.height() {
+        trace!("Layer {}", layer);
+
+        let mut new_beams = HashMap::new();
+        for (beam, count) in tachyon_beams {
+            let next_coords = beam.step(Direction::South);
+
+            if grid.get_point(next_coords) == Some(&SPLITTER_CHAR) {
+                hit_splitters.insert(next_coords);
+
+                // beams split off either side, unless that side is off the edge
+                for side in [Direction::West, Direction::East] {
+                    let nc = next_coords.step(side);
+                    if grid.contains_point(nc) {
+                        *new_beams.entry(nc).or_insert(0) += count;
+                    }
+                }
+            } else {
+                *new_beams.entry(next_coords).or_insert(0) += count;
+            }
+        }
+        tachyon_beams = new_beams;
+        layer += 1;
+    }
+
+    return (hit_splitters, tachyon_beams);
+}
+
+fn parse_input (input: &str) -> Result<Manifold, ParseError> {
+    let expected = format!("{:?}, {:?} or {:?}", START_CHAR, SPLITTER_CHAR, EMPTY_CHAR);
+    let grid = Grid::parse(input, |c| if c == START_CHAR || c == SPLITTER_CHAR || c == EMPTY_CHAR { Some(c) } else { None }, &expected)?;
+
+    let starts: Vec<(usize, usize)> = grid.iter().filter(|(_, c)| **c == START_CHAR).map(|(coords, _)| coords).collect();
+
+    let start_coords = match starts.first() {
+        Some(coords) => Point2::from(*coords),
+        None => return Err(ParseError::end_of_input(input, format!("a start position marked {:?}", START_CHAR))),
+    };
+
+    if let Some((x, y)) = starts.get(1) {
+        let line = input.lines().nth(*y).unwrap();
+        return Err(ParseError::at_column(y + 1, line, x + 1, "a single start", format!("a second {:?}", START_CHAR)));
+    }
+
+    return Ok(Manifold {
+        grid: grid,
+        start_coords: start_coords
+    })
+}
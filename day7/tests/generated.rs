@@ -0,0 +1,7 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    // every splitter that's hit adds at least one timeline
+    testing::check_generated::<day7::Day7>(day7::generator::generate, &[1, 5, 20], |part1, part2| part2.as_number() > part1.as_number());
+}
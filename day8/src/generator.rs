@@ -0,0 +1,23 @@
+// `size` junction boxes scattered through the same space as the real ones
+
+use std::collections::HashSet;
+
+use aoc_common::{geometry::Point3, random::Rng};
+
+const MAX_COORD: i64 = 99_999;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let mut junction_boxes = HashSet::new();
+    let mut lines = vec![];
+
+    // two is the fewest that can be connected
+    while lines.len() < size.max(2) {
+        let junction_box = Point3::new(rng.range(0, MAX_COORD), rng.range(0, MAX_COORD), rng.range(0, MAX_COORD));
+
+        if junction_boxes.insert(junction_box) {
+            lines.push(format!("{},{},{}", junction_box.x, junction_box.y, junction_box.z));
+        }
+    }
+
+    return lines.join("\n") + "\n";
+}
@@ -0,0 +1,211 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_common::{debug, geometry::Point3, parse, solution, stats, trace, Answer, ParseError, Solution};
+
+pub mod generator;
+
+// how many connections part 1 makes, inputs can ask for a different number with a `connections <n>` line
+const N_CONNECTIONS: usize = 1000;
+const CONNECTIONS_SETTING: &str = "connections";
+const N_LARGEST: usize = 3;
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day8>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day8>(input);
+}
+
+pub struct Day8;
+
+pub struct Playground {
+    junction_boxes: BTreeSet<Point3>,
+    // for part 1
+    n_connections: usize
+}
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    type Input = Playground;
+
+    fn parse (input: &str) -> Result<Playground, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1 (playground: &Playground) -> Answer {
+        let wiring = connect_circuits(&playground.junction_boxes, Some(playground.n_connections));
+        stats::record("connections", wiring.n_connections);
+
+        if wiring.n_connections != playground.n_connections {
+            // everything was connected before we made enough connections
+            return 0.into();
+        }
+
+        stats::record("circuits", wiring.circuits.len());
+        trace!("{:?}", wiring.circuits);
+
+        // the sort is stable, so circuits the same size stay in the order they were made
+        let mut circuit_sizes: Vec<(usize, usize)> = wiring.circuits.iter().map(|(circuit_idx, boxes)| (*circuit_idx, boxes.len())).collect();
+        circuit_sizes.sort_by(|(_a_idx, a_size), (_b_idx, b_size)| b_size.cmp(a_size));
+
+        trace!("{:?}", circuit_sizes);
+
+        let mut total = 1;
+        for i in 0..N_LARGEST {
+            total *= circuit_sizes[i].1
+        }
+
+        return total.into();
+    }
+
+    fn part2 (playground: &Playground) -> Answer {
+        let wiring = connect_circuits(&playground.junction_boxes, None);
+        stats::record("connections", wiring.n_connections);
+
+        let (box_0, box_1) = wiring.last_connection.unwrap();
+        debug!("Last two boxes: {:?}, {:?}", box_0, box_1);
+
+        return (box_0.x * box_1.x).into();
+    }
+}
+
+struct Wiring {
+    circuits: BTreeMap<usize, Vec<Point3>>,
+    n_connections: usize,
+    // the connection that joined everything into a single circuit, if we got that far
+    last_connection: Option<(Point3, Point3)>
+}
+
+// keeps connecting the closest pair of boxes until they're all in one circuit
+// or until max_connections have been made
+fn connect_circuits (junction_boxes: &BTreeSet<Point3>, max_connections: Option<usize>) -> Wiring {
+    let n_boxes = junction_boxes.len();
+    // part 1 can ask for more connections than there are pairs, so running out just stops early
+    let mut pairs = distances(junction_boxes).into_iter();
+
+    let mut n_circuits: usize = 0;
+    let mut circuit_mapping = BTreeMap::new();
+    let mut circuits = BTreeMap::new();
+
+    let mut connected_boxes = BTreeSet::new();
+
+    let mut last_connection = None;
+    let mut n_connections = 0;
+
+    while last_connection.is_none() && Some(n_connections) != max_connections {
+        let Some((_distance, box_0, box_1)) = pairs.next() else {
+            break;
+        };
+        let coords = (box_0, box_1);
+        n_connections += 1;
+
+        connected_boxes.insert(coords.0);
+        connected_boxes.insert(coords.1);
+
+        if !circuit_mapping.contains_key(&coords.0) && !circuit_mapping.contains_key(&coords.1) {
+            // neither in a circuit, create new one
+            circuit_mapping.insert(coords.0, n_circuits);
+            circuit_mapping.insert(coords.1, n_circuits);
+
+            circuits.insert(n_circuits, vec![coords.0, coords.1]);
+            trace!("Creating new circuit for boxes {:?} and {:?}", coords.0, coords.1);
+
+            n_circuits += 1;
+        } else if circuit_mapping.contains_key(&coords.0) && circuit_mapping.contains_key(&coords.1) {
+            let circuit_0_idx = *circuit_mapping.get(&coords.0).unwrap();
+            let circuit_1_idx = *circuit_mapping.get(&coords.1).unwrap();
+            if circuit_0_idx != circuit_1_idx {
+                // boxes in different circuits
+                // join circuits together
+
+                trace!("Joining circuits {} and {}", circuit_0_idx, circuit_1_idx);
+
+                let circuit_0 = circuits.get(&circuit_0_idx).unwrap().clone();
+                let circuit_1 = circuits.get_mut(&circuit_1_idx).unwrap();
+
+                trace!("Circuit {} size: {}", circuit_0_idx, circuit_0.len());
+                trace!("Circuit {} size: {}", circuit_1_idx, circuit_1.len());
+
+                for junction in circuit_0 {
+                    circuit_1.push(junction);
+                    circuit_mapping.insert(junction, circuit_1_idx);
+                }
+                circuits.remove(&circuit_0_idx);
+                trace!("New circuit {} size: {}", circuit_1_idx, circuits.get(&circuit_1_idx).unwrap().len());
+            }
+            // otherwise junction boxes in same circuit already, do nothing
+            trace!("Boxes {:?} and {:?} are in the same circuit", coords.0, coords.1);
+        } else if circuit_mapping.contains_key(&coords.0) {
+            // coord_1 not in a circuit, add to circuit_0
+            let circuit_0_idx = *circuit_mapping.get(&coords.0).unwrap();
+            circuit_mapping.insert(coords.1, circuit_0_idx);
+            circuits.get_mut(&circuit_0_idx).unwrap().push(coords.1);
+        } else if circuit_mapping.contains_key(&coords.1) {
+            // coord_0 not in a circuit, add to circuit_1
+            let circuit_1_idx = *circuit_mapping.get(&coords.1).unwrap();
+            circuit_mapping.insert(coords.0, circuit_1_idx);
+            circuits.get_mut(&circuit_1_idx).unwrap().push(coords.0);
+        }
+
+        if connected_boxes.len() == n_boxes && circuits.len() == 1 {
+            last_connection = Some(coords);
+        }
+    }
+
+    return Wiring { circuits: circuits, n_connections: n_connections, last_connection: last_connection };
+}
+
+// squared distances order pairs the same as real ones, without any floating point
+// each pair is only stored once, with the smaller box first, and they're sorted shortest first
+// ties go to the first pair in order, comparing the first boxes and then the second, where boxes
+// are ordered by x then y then z, so the same input always connects the same way
+fn distances (junction_boxes: &BTreeSet<Point3>) -> Vec<(i64, Point3, Point3)> {
+    let mut pairs = vec![];
+
+    for j1 in junction_boxes {
+        for j2 in junction_boxes {
+            if j1 < j2 {
+                pairs.push((j1.squared_euclidean(*j2), *j1, *j2));
+            }
+        }
+    }
+
+    // no two pairs are the same, so there's nothing for a stable sort to keep in order
+    pairs.sort_unstable();
+    return pairs;
+}
+
+// the connections setting can only come first, before any boxes
+fn parse_input (input: &str) -> Result<Playground, ParseError> {
+    let mut junction_boxes = BTreeSet::new();
+    let mut n_connections = N_CONNECTIONS;
+
+    for (line_idx, line) in parse::lines(input).into_iter().enumerate() {
+        if line.text.starts_with(CONNECTIONS_SETTING) {
+            if line_idx > 0 {
+                return Err(line.error("a junction box, the connections setting has to come first"));
+            }
+            let value = line.slice(CONNECTIONS_SETTING.len()..).trim();
+            n_connections = value.parse::<usize>("a number of connections")?;
+            if n_connections == 0 {
+                return Err(value.error("at least 1 connection"));
+            }
+            continue;
+        }
+
+        let [x, y, z] = line.tuple::<i64, 3>(",", "a coordinate")?;
+
+        // a box listed twice would be zero distance from itself
+        if !junction_boxes.insert(Point3::new(x, y, z)) {
+            return Err(line.error("a junction box that isn't already listed"));
+        }
+    }
+
+    // need at least two boxes to make a connection
+    if junction_boxes.len() < 2 {
+        return Err(ParseError::end_of_input(input, "at least 2 junction boxes"));
+    }
+
+    return Ok(Playground { junction_boxes: junction_boxes, n_connections: n_connections });
+}
@@ -0,0 +1,6 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    testing::check_generated::<day8::Day8>(day8::generator::generate, &[2, 10, 40], testing::any_answers);
+}
@@ -0,0 +1,98 @@
+// red tiles at the corners of a rectilinear polygon with `size` columns
+//
+// the polygon is built on a small lattice out of vertical bars side by side, each overlapping its
+// neighbours, so it can't cross itself or have holes, then the lattice lines are spread out to
+// tile coordinates the size of the real ones
+
+use aoc_common::{geometry::Point2, random::Rng};
+
+// the tallest a bar can be, in lattice units
+const MAX_BAR_HEIGHT: i64 = 12;
+// the gap between neighbouring lattice lines, in tiles
+// at least 2 so there's always a tile between two edges that face each other
+const MIN_GAP: i64 = 2;
+const MAX_GAP: i64 = 2_000;
+
+pub fn generate (rng: &mut Rng, size: usize) -> String {
+    let n_bars = size.max(1);
+
+    // each bar covers lattice rows bottom..top, and shares at least one row with the bar before
+    let mut bars: Vec<(i64, i64)> = vec![];
+    for _ in 0..n_bars {
+        let bar = match bars.last() {
+            None => {
+                let bottom = rng.range(0, MAX_BAR_HEIGHT - 1);
+                (bottom, rng.range(bottom + 1, MAX_BAR_HEIGHT))
+            },
+            Some((previous_bottom, previous_top)) => {
+                let shared = rng.range(*previous_bottom, previous_top - 1);
+                (rng.range(0, shared), rng.range(shared + 1, MAX_BAR_HEIGHT))
+            },
+        };
+        bars.push(bar);
+    }
+
+    // along the tops left to right, then back along the bottoms
+    let mut corners = vec![];
+    for (x, (_bottom, top)) in bars.iter().enumerate() {
+        corners.push(Point2::new(x as i64, *top));
+        corners.push(Point2::new(x as i64 + 1, *top));
+    }
+    for (x, (bottom, _top)) in bars.iter().enumerate().rev() {
+        corners.push(Point2::new(x as i64 + 1, *bottom));
+        corners.push(Point2::new(x as i64, *bottom));
+    }
+    let corners = remove_straight_corners(corners);
+
+    let xs = spread_out(rng, n_bars as i64);
+    let ys = spread_out(rng, MAX_BAR_HEIGHT);
+
+    // half the time on its side, so it isn't always the bars that are vertical
+    let on_side = rng.chance(0.5);
+
+    let mut lines = vec![];
+    for corner in corners {
+        let (x, y) = (xs[corner.x as usize], ys[corner.y as usize]);
+        if on_side {
+            lines.push(format!("{},{}", y, x));
+        } else {
+            lines.push(format!("{},{}", x, y));
+        }
+    }
+
+    return lines.join("\n") + "\n";
+}
+
+// neighbouring bars the same height leave points in the middle of an edge, and two bars meeting
+// leave the same point twice, so keep only the points where the boundary actually turns
+fn remove_straight_corners (mut points: Vec<Point2>) -> Vec<Point2> {
+    loop {
+        let n_points = points.len();
+        let straight = (0..n_points).find(|idx| {
+            let before = points[(idx + n_points - 1) % n_points];
+            let point = points[*idx];
+            let after = points[(idx + 1) % n_points];
+
+            return point == after || (before.x == point.x && point.x == after.x) || (before.y == point.y && point.y == after.y);
+        });
+
+        match straight {
+            Some(idx) => {
+                points.remove(idx);
+            },
+            None => return points,
+        }
+    }
+}
+
+// tile coordinates for lattice lines 0..=n_lines, in order with random gaps between them
+fn spread_out (rng: &mut Rng, n_lines: i64) -> Vec<i64> {
+    let mut coords = vec![rng.range(0, MAX_GAP)];
+
+    for _ in 0..n_lines {
+        let next = coords.last().unwrap() + rng.range(MIN_GAP, MAX_GAP);
+        coords.push(next);
+    }
+
+    return coords;
+}
@@ -0,0 +1,281 @@
+use std::collections::HashSet;
+
+use aoc_common::{debug, geometry::{BoundingBox, Direction, Point2}, parse, solution, timing::Timer, trace, Answer, ParseError, Solution};
+
+pub mod generator;
+
+pub fn part1 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part1::<Day9>(input);
+}
+
+pub fn part2 (input: &str) -> Result<Answer, ParseError> {
+    return solution::solve_part2::<Day9>(input);
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    type Input = Vec<Point2>;
+
+    fn parse (input: &str) -> Result<Vec<Point2>, ParseError> {
+        return parse_input(input);
+    }
+
+    fn part1 (red_tiles: &Vec<Point2>) -> Answer {
+        return max_area(red_tiles).into();
+    }
+
+    fn part2 (red_tiles: &Vec<Point2>) -> Answer {
+        return max_coloured_area(red_tiles).into();
+    }
+}
+
+fn max_area (red_tiles: &Vec<Point2>) -> i64 {
+    let n_red_tiles = red_tiles.len();
+
+    let mut max_area = 0;
+    
+    for i in 0..n_red_tiles {
+        let rt1 = red_tiles[i];
+
+        for j in (i+1)..n_red_tiles {
+            let rt2 = red_tiles[j];
+
+            let area = BoundingBox::from_corners(rt1, rt2).area();
+            if area > max_area {
+                max_area = area;
+            }
+        }
+    }
+
+    return max_area;
+}
+
+// largest rectangle between two red tiles that only contains red or green tiles
+fn max_coloured_area (red_tiles: &Vec<Point2>) -> i64 {
+    let timer = Timer::start();
+    let extrema = BoundingBox::from_points(red_tiles.iter().cloned()).unwrap();
+    let n_red_tiles = red_tiles.len();
+
+    let mut boundary = vec![];
+
+    for i in 0..n_red_tiles {
+        let v0 = red_tiles[i];
+        let v1 = red_tiles[(i + 1) % n_red_tiles];
+
+        boundary.append(&mut calc_edge(v0, v1));
+    }
+
+    timer.report("Calculated boundary");
+    trace!("Boundary:");
+    trace!("{:?}", boundary);
+    
+    let boundary_set: HashSet<Point2> = HashSet::from_iter(boundary.iter().cloned());
+
+    // walk in from just outside the bottom right corner until we hit the boundary
+    let mut border_point = Point2::new(extrema.max.x + 1, extrema.max.y);
+    let mut direction = Direction::South;
+
+    loop {
+        let next_border_point = border_point.step(Direction::West);
+        if boundary_set.contains(&next_border_point) {
+            break;
+        }
+        border_point = next_border_point;
+    }
+
+    let start_point = border_point;
+
+    trace!("Bounding box:");
+    trace!("{:?}", extrema);
+
+    let mut border = vec![];
+    border.push(start_point);
+
+    trace!("starting at: {:?}", start_point);
+
+    loop {
+        trace!("Border point is: {:?}", border_point);
+        // next point in same direction
+        let next_border_point = border_point.step(direction);
+        trace!("Next border point is: {:?}", next_border_point);
+        // point to the right of this direction, which is where the boundary should be
+        let possible_boundary_point = border_point.step(direction.rotate_right());
+        trace!("Next righthand point is: {:?}", possible_boundary_point);
+
+        if next_border_point == start_point {
+            // finished
+            break
+        }
+
+        if boundary_set.contains(&next_border_point) {
+            trace!("Next border point is on the boundary");
+            // next point along this direction is in the boundary, turn away from it
+            border.push(border_point);
+
+            direction = direction.rotate_left();
+            continue
+        }
+
+        if !boundary_set.contains(&possible_boundary_point) {
+            trace!("Next righthand point is not on the boundary");
+            // point to the right is not in the boundary, turn to follow it round the corner
+            border.push(border_point);
+            direction = direction.rotate_right();
+            border_point = possible_boundary_point;
+            // the start can be come back to round a corner as well as straight on
+            if border_point == start_point {
+                break
+            }
+            continue
+        }
+
+        // still tracking the border, continue
+        border_point = next_border_point;
+    }
+
+    timer.report("Calculated border");
+    trace!("{:?}", border);
+
+    let n_border_coords = border.len();
+
+    let mut part_2_max_area = 0;
+    let mut best_rect = None;
+
+    for i in 0..n_red_tiles {
+        let rt1 = red_tiles[i];
+
+        for j in (i+1)..n_red_tiles {
+            let rt2 = red_tiles[j];
+            let rect = BoundingBox::from_corners(rt1, rt2);
+            let area = rect.area();
+
+            let mut all_coloured_tiles = true;
+
+            if area <= part_2_max_area {
+                continue;
+            }
+
+            for border_idx in 0..n_border_coords {
+                let border_v1 = border[border_idx];
+                let border_v2 = border[(border_idx + 1) % n_border_coords];
+
+                if line_crosses_rect((border_v1, border_v2), &rect) || rect.contains(border_v1) {
+                    all_coloured_tiles = false;
+                    break;
+                }
+            }
+
+            if all_coloured_tiles {
+                best_rect = Some(rect);
+                part_2_max_area = area;
+            }
+        }
+    }
+
+    debug!("Largest rectangle is {:?}", best_rect);
+    timer.report("Part 2 ran");
+
+    return part_2_max_area;
+}
+
+// whether an axis-aligned line cuts all the way across the rectangle
+fn line_crosses_rect ((c1, c2): (Point2, Point2), rect: &BoundingBox) -> bool {
+    if c1.x == c2.x {
+        // x is constant
+        if c1.x <= rect.min.x || rect.max.x <= c1.x {
+            // x doesn't fall within rect
+            return false
+        }
+        // y crosses rect, in either direction
+        return (c1.y <= rect.min.y && rect.max.y <= c2.y) || (c2.y <= rect.min.y && rect.max.y <= c1.y)
+    } else {
+        // y is constant
+        if c1.y <= rect.min.y || rect.max.y <= c1.y {
+            // y doesn't fall within rect
+            return false
+        }
+        // x crosses rect, in either direction
+        return (c1.x <= rect.min.x && rect.max.x <= c2.x) || (c2.x <= rect.min.x && rect.max.x <= c1.x)
+    }
+}
+
+// every point on the straight line from one vertex to the next, both ends included
+// the vertices have to share a row or a column, which `parse_input` makes sure of
+fn calc_edge (vertex_0: Point2, vertex_1: Point2) -> Vec<Point2> {
+    let step = (vertex_1 - vertex_0).signum();
+    let mut edge = vec![vertex_0];
+    let mut point = vertex_0;
+
+    while point != vertex_1 {
+        point += step;
+        edge.push(point);
+    }
+    return edge;
+}
+
+fn parse_input (input: &str) -> Result<Vec<Point2>, ParseError> {
+    let lines = parse::lines(input);
+    let mut red_tiles = vec![];
+
+    for line in lines.iter() {
+        let [x, y] = line.tuple::<i64, 2>(",", "a coordinate")?;
+        let red_tile = Point2::new(x, y);
+
+        if red_tiles.last().is_some_and(|previous| !in_line(*previous, red_tile)) {
+            return Err(line.error("a red tile in the same row or column as the one before"));
+        }
+        if red_tiles.last() == Some(&red_tile) {
+            return Err(line.error("a red tile other than the one before"));
+        }
+        if red_tiles.len() >= 2 && doubles_back(red_tiles[red_tiles.len() - 2], red_tiles[red_tiles.len() - 1], red_tile) {
+            return Err(line.error("a red tile that doesn't go back along the edge before"));
+        }
+        red_tiles.push(red_tile);
+    }
+
+    // the tiles are the corners of a polygon
+    if red_tiles.len() < 3 {
+        return Err(ParseError::end_of_input(input, "at least 3 red tiles"));
+    }
+    // which goes back round to the first, without either end of that edge doubling back
+    let (last, last_line) = (red_tiles[red_tiles.len() - 1], lines[red_tiles.len() - 1]);
+    if !in_line(last, red_tiles[0]) {
+        return Err(last_line.error("a red tile in the same row or column as the first"));
+    }
+    if last == red_tiles[0] {
+        return Err(last_line.error("a red tile other than the first, the polygon closes itself"));
+    }
+    if doubles_back(red_tiles[red_tiles.len() - 2], last, red_tiles[0]) || doubles_back(last, red_tiles[0], red_tiles[1]) {
+        return Err(last_line.error("a red tile that doesn't go back along an edge when joined to the first"));
+    }
+
+    // edges that cross or touch, other than where each one joins the next, don't have a single inside
+    // for the border to walk round. each edge is its own bounding box, so they meet if their boxes do
+    let n_red_tiles = red_tiles.len();
+    let edges: Vec<BoundingBox> = (0..n_red_tiles).map(|i| BoundingBox::from_corners(red_tiles[i], red_tiles[(i + 1) % n_red_tiles])).collect();
+    for i in 0..n_red_tiles {
+        for j in (i + 2)..n_red_tiles {
+            // the last edge joins back onto the first
+            if i == 0 && j == n_red_tiles - 1 {
+                continue;
+            }
+            if edges[i].overlaps(&edges[j]) {
+                return Err(lines[(j + 1).min(n_red_tiles - 1)].error("a red tile whose edge doesn't cross or touch an earlier one"));
+            }
+        }
+    }
+
+    return Ok(red_tiles);
+}
+
+// the edges between red tiles only go straight across or straight down
+fn in_line (a: Point2, b: Point2) -> bool {
+    return a.x == b.x || a.y == b.y;
+}
+
+// whether going from a to b and on to c turns right round, which would give an edge no inside
+fn doubles_back (a: Point2, b: Point2, c: Point2) -> bool {
+    return (b - a).signum() + (c - b).signum() == Point2::ORIGIN;
+}
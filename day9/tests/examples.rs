@@ -0,0 +1,4 @@
+#[test]
+fn examples () {
+    aoc_common::testing::check_examples::<day9::Day9>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+
+#[test]
+fn generated () {
+    // part 2 picks from the same rectangles with one more rule
+    testing::check_generated::<day9::Day9>(day9::generator::generate, &[1, 5, 20], |part1, part2| part2.as_number() <= part1.as_number());
+}
@@ -0,0 +1,24 @@
+use aoc_common::Solution;
+use day9::Day9;
+
+#[test]
+fn polygons_without_an_inside_are_errors () {
+    // straight back along the edge it came from
+    let error = Day9::parse("0,0\n5,0\n0,0\n").err().unwrap();
+    assert_eq!(error.line, 3);
+
+    // the same tile twice
+    let error = Day9::parse("0,0\n5,0\n5,0\n5,3\n").err().unwrap();
+    assert_eq!(error.line, 3);
+
+    // the edge from 1,7 to 7,7 is crossed by the one from 6,6 to 6,8
+    let error = Day9::parse("0,0\n8,0\n8,4\n1,4\n1,7\n7,7\n7,6\n6,6\n6,8\n0,8\n").err().unwrap();
+    assert_eq!(error.line, 9);
+}
+
+#[test]
+fn the_border_walk_ends_round_a_corner () {
+    // the walk comes back to where it started by turning rather than going straight on
+    let red_tiles = Day9::parse("0,0\n7,0\n7,1\n6,1\n6,2\n0,2\n").unwrap();
+    assert_eq!(Day9::part2(&red_tiles).to_string(), "21");
+}